@@ -0,0 +1,90 @@
+use anyhow::bail;
+use bech32::{FromBase32, ToBase32, Variant};
+
+/// Decodes a bech32 address into its human readable prefix and raw bytes.
+pub fn decode(address: &str) -> Result<(String, Vec<u8>), anyhow::Error> {
+    let (prefix, data, variant) = bech32::decode(address)?;
+
+    if variant != Variant::Bech32 {
+        bail!("unsupported bech32m address: {}", address);
+    }
+
+    Ok((prefix, Vec::<u8>::from_base32(&data)?))
+}
+
+pub fn encode(prefix: &str, data: &[u8]) -> Result<String, anyhow::Error> {
+    Ok(bech32::encode(prefix, data.to_base32(), Variant::Bech32)?)
+}
+
+/// Re-encodes an address for another chain sharing the same key, e.g.
+/// `cosmos1…` to `osmo1…`.
+pub fn convert_prefix(address: &str, prefix: &str) -> Result<String, anyhow::Error> {
+    let (_, data) = decode(address)?;
+
+    encode(prefix, &data)
+}
+
+pub fn validate(address: &str, prefix: &str) -> Result<(), anyhow::Error> {
+    let (address_prefix, data) = decode(address)?;
+
+    if address_prefix != prefix {
+        bail!(
+            "address {} has prefix {}, expected {}",
+            address,
+            address_prefix,
+            prefix
+        );
+    }
+
+    if data.len() != 20 && data.len() != 32 {
+        bail!("invalid address length {} for {}", data.len(), address);
+    }
+
+    Ok(())
+}
+
+const EVM_BLOCKCHAINS: [&str; 8] = [
+    "ETH", "EVM", "MATIC", "POLYGON", "BSC", "AVAX", "FTM", "ARB",
+];
+const COSMOS_BLOCKCHAINS: [&str; 10] = [
+    "ATOM", "COSMOS", "OSMO", "JUNO", "KUJI", "SCRT", "BLD", "STARS", "XKI", "TKI",
+];
+
+pub fn is_evm(blockchain: &str) -> bool {
+    EVM_BLOCKCHAINS.contains(&blockchain.to_uppercase().as_str())
+}
+
+/// Compares two addresses of `blockchain`, ignoring case for EVM addresses
+/// whose checksum is only carried by the case.
+pub fn same_address(address: &str, other: &str, blockchain: &str) -> bool {
+    if is_evm(blockchain) {
+        address.eq_ignore_ascii_case(other)
+    } else {
+        address == other
+    }
+}
+
+/// Checks the address format expected by a Taurus blockchain; blockchains
+/// without a known format are accepted as is.
+pub fn validate_for_blockchain(address: &str, blockchain: &str) -> Result<(), anyhow::Error> {
+    let blockchain = blockchain.to_uppercase();
+
+    if is_evm(blockchain.as_str()) {
+        let hex = address.strip_prefix("0x").ok_or_else(|| {
+            anyhow::anyhow!("{} address must start with 0x: {}", blockchain, address)
+        })?;
+        if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("invalid {} address: {}", blockchain, address);
+        }
+    } else if COSMOS_BLOCKCHAINS.contains(&blockchain.as_str()) {
+        decode(address)?;
+    } else if blockchain == "BTC" {
+        let valid_start =
+            address.starts_with('1') || address.starts_with('3') || address.starts_with("bc1");
+        if !valid_start || address.len() < 26 || address.len() > 62 {
+            bail!("invalid BTC address: {}", address);
+        }
+    }
+
+    Ok(())
+}
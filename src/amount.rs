@@ -0,0 +1,135 @@
+use anyhow::{anyhow, bail};
+use std::fmt;
+
+/// A base-unit value paired with the currency decimals and symbol needed to
+/// display it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Amount {
+    pub value: u128,
+    pub decimals: u32,
+    pub symbol: String,
+}
+
+impl Amount {
+    pub fn new(value: u128, decimals: u32, symbol: &str) -> Self {
+        Amount {
+            value,
+            decimals,
+            symbol: symbol.to_string(),
+        }
+    }
+
+    /// Parses a human readable value such as `"1.25"` into base units, refusing
+    /// any precision the currency can not represent.
+    pub fn from_decimal_str(
+        value: &str,
+        decimals: u32,
+        symbol: &str,
+    ) -> Result<Self, anyhow::Error> {
+        let value = value.trim();
+        let (int_part, frac_part) = match value.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, frac_part),
+            None => (value, ""),
+        };
+
+        if int_part.is_empty() && frac_part.is_empty() {
+            bail!("empty amount");
+        }
+        if !int_part.chars().all(|c| c.is_ascii_digit())
+            || !frac_part.chars().all(|c| c.is_ascii_digit())
+        {
+            bail!("invalid amount: {}", value);
+        }
+
+        let frac_part = frac_part.trim_end_matches('0');
+        if frac_part.len() > decimals as usize {
+            bail!(
+                "amount {} has more than {} decimals for {}",
+                value,
+                decimals,
+                symbol
+            );
+        }
+
+        let scale = Self::scale(decimals)?;
+        let int_value = if int_part.is_empty() {
+            0
+        } else {
+            int_part.parse::<u128>()?
+        };
+        let frac_value = if frac_part.is_empty() {
+            0
+        } else {
+            frac_part.parse::<u128>()? * Self::scale(decimals - frac_part.len() as u32)?
+        };
+
+        let value = int_value
+            .checked_mul(scale)
+            .and_then(|v| v.checked_add(frac_value))
+            .ok_or_else(|| anyhow!("amount overflow: {}", value))?;
+
+        Ok(Amount::new(value, decimals, symbol))
+    }
+
+    /// Formats the value with its decimals, trailing zeros removed.
+    pub fn to_decimal_string(&self) -> String {
+        if self.decimals == 0 {
+            return self.value.to_string();
+        }
+
+        let digits = format!(
+            "{:0>width$}",
+            self.value,
+            width = self.decimals as usize + 1
+        );
+        let (int_part, frac_part) = digits.split_at(digits.len() - self.decimals as usize);
+        let frac_part = frac_part.trim_end_matches('0');
+
+        if frac_part.is_empty() {
+            int_part.to_string()
+        } else {
+            format!("{}.{}", int_part, frac_part)
+        }
+    }
+
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount, anyhow::Error> {
+        self.check_same_currency(other)?;
+
+        let value = self
+            .value
+            .checked_add(other.value)
+            .ok_or_else(|| anyhow!("overflow adding {} to {}", other, self))?;
+
+        Ok(Amount::new(value, self.decimals, &self.symbol))
+    }
+
+    pub fn checked_sub(&self, other: &Amount) -> Result<Amount, anyhow::Error> {
+        self.check_same_currency(other)?;
+
+        let value = self
+            .value
+            .checked_sub(other.value)
+            .ok_or_else(|| anyhow!("insufficient amount: {} < {}", self, other))?;
+
+        Ok(Amount::new(value, self.decimals, &self.symbol))
+    }
+
+    fn check_same_currency(&self, other: &Amount) -> Result<(), anyhow::Error> {
+        if self.symbol != other.symbol || self.decimals != other.decimals {
+            bail!("currency mismatch: {} != {}", self.symbol, other.symbol);
+        }
+        Ok(())
+    }
+
+    fn scale(decimals: u32) -> Result<u128, anyhow::Error> {
+        10u128
+            .checked_pow(decimals)
+            .ok_or_else(|| anyhow!("unsupported decimals: {}", decimals))
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.symbol)
+    }
+}
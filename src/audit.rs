@@ -0,0 +1,115 @@
+use crate::date;
+use crate::taurus::{RequestFilter, RequestInfos, Taurus};
+use anyhow::bail;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::io::Write;
+
+/// One trail entry of a request, flattened for compliance reports.
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct AuditEvent {
+    pub date: Option<DateTime<Utc>>,
+    pub request_id: String,
+    pub request_type: String,
+    pub currency: String,
+    pub user_id: String,
+    pub external_user_id: String,
+    pub action: String,
+    /// Status of the request right after this action.
+    pub request_status: String,
+}
+
+/// Flattens the trails of `requests`, oldest first, undated events last.
+pub fn audit_events(requests: &[RequestInfos]) -> Vec<AuditEvent> {
+    let mut events = Vec::new();
+
+    for request in requests {
+        for trail in &request.trails {
+            events.push(AuditEvent {
+                date: trail.date,
+                request_id: request.id.clone(),
+                request_type: request.type_request.clone(),
+                currency: request.currency.clone(),
+                user_id: trail.user_id.clone(),
+                external_user_id: trail.external_user_id.clone(),
+                action: trail.action.clone(),
+                request_status: trail.request_status.clone(),
+            });
+        }
+    }
+
+    events.sort_by_key(|e| (e.date.is_none(), e.date));
+
+    events
+}
+
+/// Trail events of an audit range, see [`fetch`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Audit {
+    /// Events in the range, oldest first.
+    pub events: Vec<AuditEvent>,
+    /// Events without a date, which cannot be placed in or out of the range.
+    pub undated: Vec<AuditEvent>,
+}
+
+/// Returns the trail events that happened in `[from, to)`.
+///
+/// Taurus filters requests by creation date, so only requests created up to
+/// `lookback` before `from` are searched: events of older requests, e.g. an
+/// approval weeks after the request, are missed when `lookback` is too short.
+/// Undated events of the searched requests are returned apart.
+pub fn fetch(
+    taurus: &Taurus,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    lookback: Duration,
+) -> Result<Audit, anyhow::Error> {
+    if from >= to {
+        bail!("empty audit range {} - {}", from, to);
+    }
+    if lookback < Duration::zero() {
+        bail!("negative audit lookback {}", lookback);
+    }
+
+    let requests = taurus.all_requests(&RequestFilter {
+        from: Some(date::format(&(from - lookback))),
+        to: Some(date::format(&to)),
+        ..Default::default()
+    })?;
+
+    let (events, undated): (Vec<AuditEvent>, Vec<AuditEvent>) = audit_events(&requests)
+        .into_iter()
+        .partition(|e| e.date.is_some());
+
+    Ok(Audit {
+        events: events
+            .into_iter()
+            .filter(|e| e.date.is_some_and(|date| date >= from && date < to))
+            .collect(),
+        undated,
+    })
+}
+
+pub fn write_csv<W: Write>(events: &[AuditEvent], writer: W) -> Result<(), anyhow::Error> {
+    let mut writer = csv::Writer::from_writer(writer);
+
+    for event in events {
+        writer.serialize(event)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+pub fn write_json_lines<W: Write>(
+    events: &[AuditEvent],
+    mut writer: W,
+) -> Result<(), anyhow::Error> {
+    for event in events {
+        serde_json::to_writer(&mut writer, event)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
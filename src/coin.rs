@@ -0,0 +1,268 @@
+use anyhow::{anyhow, bail};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Checks a denom against the Cosmos-SDK rule `[a-zA-Z][a-zA-Z0-9/:._-]{2,127}`.
+pub fn validate_denom(denom: &str) -> Result<(), anyhow::Error> {
+    if denom.len() < 3 || denom.len() > 128 {
+        bail!("invalid denom length: {}", denom);
+    }
+
+    let mut chars = denom.chars();
+    if !chars.next().is_some_and(|c| c.is_ascii_alphabetic()) {
+        bail!("denom must start with a letter: {}", denom);
+    }
+
+    if !chars.all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c)) {
+        bail!("invalid character in denom: {}", denom);
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Coin {
+    pub denom: String,
+    #[serde(serialize_with = "serialize_amount")]
+    pub amount: u128,
+}
+
+fn serialize_amount<S: Serializer>(amount: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&amount.to_string())
+}
+
+fn deserialize_amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+    serde_aux::prelude::deserialize_number_from_string(deserializer)
+}
+
+// goes through `Coin::new` so that the denom is validated
+impl<'de> Deserialize<'de> for Coin {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawCoin {
+            denom: String,
+            #[serde(deserialize_with = "deserialize_amount")]
+            amount: u128,
+        }
+
+        let coin = RawCoin::deserialize(deserializer)?;
+        Coin::new(coin.amount, coin.denom.as_str()).map_err(D::Error::custom)
+    }
+}
+
+impl Coin {
+    pub fn new(amount: u128, denom: &str) -> Result<Self, anyhow::Error> {
+        validate_denom(denom)?;
+
+        Ok(Coin {
+            denom: denom.to_string(),
+            amount,
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    pub fn checked_add(&self, other: &Coin) -> Result<Coin, anyhow::Error> {
+        if self.denom != other.denom {
+            bail!("denom mismatch: {} != {}", self.denom, other.denom);
+        }
+
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or_else(|| anyhow!("overflow adding {} to {}", other, self))?;
+
+        Ok(Coin {
+            denom: self.denom.clone(),
+            amount,
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Coin) -> Result<Coin, anyhow::Error> {
+        if self.denom != other.denom {
+            bail!("denom mismatch: {} != {}", self.denom, other.denom);
+        }
+
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or_else(|| anyhow!("insufficient funds: {} < {}", self, other))?;
+
+        Ok(Coin {
+            denom: self.denom.clone(),
+            amount,
+        })
+    }
+}
+
+impl fmt::Display for Coin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+impl FromStr for Coin {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(|| anyhow!("missing denom in coin: {}", s))?;
+
+        if split == 0 {
+            bail!("missing amount in coin: {}", s);
+        }
+
+        let (amount, denom) = s.split_at(split);
+        Coin::new(amount.parse()?, denom)
+    }
+}
+
+/// A sorted set of coins with at most one entry per denom and no zero amounts,
+/// matching `sdk.Coins` semantics.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Coins(Vec<Coin>);
+
+impl Coins {
+    pub fn new() -> Self {
+        Coins(Vec::new())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Coin> {
+        self.0.iter()
+    }
+
+    pub fn amount_of(&self, denom: &str) -> u128 {
+        self.0
+            .iter()
+            .find(|c| c.denom == denom)
+            .map_or(0, |c| c.amount)
+    }
+
+    pub fn checked_add(&self, other: &Coins) -> Result<Coins, anyhow::Error> {
+        let mut out = self.clone();
+        for coin in other.iter() {
+            out.add_coin(coin)?;
+        }
+        Ok(out)
+    }
+
+    pub fn checked_sub(&self, other: &Coins) -> Result<Coins, anyhow::Error> {
+        let mut out = self.clone();
+        for coin in other.iter() {
+            out.sub_coin(coin)?;
+        }
+        Ok(out)
+    }
+
+    fn add_coin(&mut self, coin: &Coin) -> Result<(), anyhow::Error> {
+        if coin.is_zero() {
+            return Ok(());
+        }
+
+        match self.0.binary_search_by(|c| c.denom.cmp(&coin.denom)) {
+            Ok(idx) => self.0[idx] = self.0[idx].checked_add(coin)?,
+            Err(idx) => self.0.insert(idx, coin.clone()),
+        }
+
+        Ok(())
+    }
+
+    fn sub_coin(&mut self, coin: &Coin) -> Result<(), anyhow::Error> {
+        if coin.is_zero() {
+            return Ok(());
+        }
+
+        match self.0.binary_search_by(|c| c.denom.cmp(&coin.denom)) {
+            Ok(idx) => {
+                let left = self.0[idx].checked_sub(coin)?;
+                if left.is_zero() {
+                    self.0.remove(idx);
+                } else {
+                    self.0[idx] = left;
+                }
+                Ok(())
+            }
+            Err(_) => bail!("insufficient funds: no {} to subtract from", coin.denom),
+        }
+    }
+}
+
+impl TryFrom<Vec<Coin>> for Coins {
+    type Error = anyhow::Error;
+
+    fn try_from(coins: Vec<Coin>) -> Result<Self, Self::Error> {
+        let mut out = Coins::new();
+        for coin in coins.iter() {
+            validate_denom(&coin.denom)?;
+            out.add_coin(coin)?;
+        }
+        Ok(out)
+    }
+}
+
+impl From<Coin> for Coins {
+    fn from(coin: Coin) -> Self {
+        if coin.is_zero() {
+            Coins::new()
+        } else {
+            Coins(vec![coin])
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Coins {
+    type Item = &'a Coin;
+    type IntoIter = std::slice::Iter<'a, Coin>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for Coins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let coins: Vec<String> = self.0.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", coins.join(","))
+    }
+}
+
+impl FromStr for Coins {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coins = s
+            .split(',')
+            .filter(|c| !c.trim().is_empty())
+            .map(Coin::from_str)
+            .collect::<Result<Vec<Coin>, anyhow::Error>>()?;
+
+        Coins::try_from(coins)
+    }
+}
+
+impl Serialize for Coins {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Coins {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let coins = Vec::<Coin>::deserialize(deserializer)?;
+        Coins::try_from(coins).map_err(D::Error::custom)
+    }
+}
@@ -1,23 +1,125 @@
+use crate::coin::{Coin, Coins};
+use crate::taurus::BroadcastKind;
 use anyhow::bail;
 use log::{debug, trace};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
+use std::str::FromStr;
 
-#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
 pub struct Chain {
     pub prefix: String,
     pub denom: String,
     pub lcd: String,
     pub rpc: String,
     pub chain_id: String,
+    /// Gas price in `denom` base units, as a decimal string such as `"0.025"`.
+    #[serde(default)]
+    pub gas_price: Option<String>,
+    #[serde(default)]
+    pub gas_adjustment: Option<f64>,
+    #[serde(default)]
+    pub default_gas_limit: Option<u64>,
+    /// Highest fee accepted per denom, e.g. `"50000uatom"`; fees in a denom
+    /// missing from it are refused.
+    #[serde(default)]
+    pub max_fee: Option<String>,
+    /// Denoms accepted to pay fees, `denom` only when unset.
+    #[serde(default)]
+    pub fee_denoms: Option<Vec<String>>,
+    #[serde(default)]
+    pub broadcast_kind: Option<BroadcastKind>,
+    /// Cosmos-SDK version run by the chain, e.g. `"0.47.5"`.
+    #[serde(default)]
+    pub sdk_version: Option<String>,
+    /// Taurus currency id of `denom`, see `Currencies::for_chain`.
+    #[serde(default)]
+    pub currency_id: Option<String>,
+}
+
+impl Chain {
+    pub fn validate_address(&self, address: &str) -> Result<(), anyhow::Error> {
+        crate::address::validate(address, self.prefix.as_str())
+    }
+
+    pub fn convert_address(&self, address: &str) -> Result<String, anyhow::Error> {
+        crate::address::convert_prefix(address, self.prefix.as_str())
+    }
+
+    pub fn broadcast_kind(&self) -> BroadcastKind {
+        self.broadcast_kind
+            .unwrap_or_default()
+            .for_sdk_version(self.sdk_version.as_deref())
+    }
+
+    pub fn gas_adjustment(&self) -> f64 {
+        self.gas_adjustment
+            .unwrap_or(crate::simulate::DEFAULT_GAS_ADJUSTMENT)
+    }
+
+    pub fn fee_denoms(&self) -> Vec<String> {
+        match &self.fee_denoms {
+            Some(denoms) if !denoms.is_empty() => denoms.clone(),
+            _ => vec![self.denom.clone()],
+        }
+    }
+
+    pub fn max_fee(&self) -> Result<Option<Coins>, anyhow::Error> {
+        self.max_fee
+            .as_ref()
+            .map(|fee| Coins::from_str(fee.as_str()))
+            .transpose()
+    }
+
+    /// Refuses fees paid in a denom not accepted by the chain or above the
+    /// configured `max_fee`.
+    pub fn check_fee(&self, fee: &Coin) -> Result<(), anyhow::Error> {
+        if !self.fee_denoms().contains(&fee.denom) {
+            bail!("fee denom {} not accepted on {}", fee.denom, self.chain_id);
+        }
+
+        if let Some(max_fee) = self.max_fee()? {
+            let max = max_fee.amount_of(fee.denom.as_str());
+            if fee.amount > max {
+                bail!(
+                    "fee {} exceeds max fee {}{} on {}",
+                    fee,
+                    max,
+                    fee.denom,
+                    self.chain_id
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthMode {
+    /// `mail` and `passwd` login.
+    #[default]
+    Password,
+    /// `api_key` and `api_secret`, every request being signed.
+    ApiKey,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct Taurus {
     pub api_url: String,
+    #[serde(default)]
     pub mail: String,
+    #[serde(default)]
     pub passwd: String,
+    #[serde(default)]
+    pub auth: AuthMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_secret: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
@@ -26,12 +128,43 @@ pub struct Wallet {
     pub address: String,
 }
 
+/// A notification channel, e.g. in the config file:
+///
+/// ```toml
+/// [[notifier]]
+/// kind = "webhook"
+/// url = "https://relay.example.com/taurus"
+/// headers = { Authorization = "Bearer xxx" }
+/// ```
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum NotifierConfig {
+    Slack {
+        web_hook: String,
+    },
+    Webhook {
+        url: String,
+        #[serde(default)]
+        headers: BTreeMap<String, String>,
+    },
+    Log {
+        #[serde(default)]
+        level: Option<String>,
+    },
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct Config {
     pub taurus: Taurus,
     pub chain: Vec<Chain>,
     pub wallet: Vec<Wallet>,
     pub slack_web_hook: Option<String>,
+    /// Highest risk score accepted for a destination, see
+    /// `Taurus::safe_whitelisted`.
+    #[serde(default)]
+    pub max_risk_score: Option<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notifier: Vec<NotifierConfig>,
 }
 
 impl Config {
@@ -51,6 +184,9 @@ impl Config {
                 api_url: "taurus.io".to_string(),
                 mail: "taurus@taurus.io".to_string(),
                 passwd: "password".to_string(),
+                auth: AuthMode::Password,
+                api_key: None,
+                api_secret: None,
             },
             chain: vec![
                 Chain {
@@ -59,6 +195,7 @@ impl Config {
                     lcd: "https://api-challenge.blockchain.ki".to_string(),
                     rpc: "https://rpc-challenge.blockchain.ki".to_string(),
                     chain_id: "kichain-t-4".to_string(),
+                    ..Default::default()
                 },
                 Chain {
                     prefix: "xki".to_string(),
@@ -66,6 +203,7 @@ impl Config {
                     lcd: "https://api-mainnet.blockchain.ki".to_string(),
                     rpc: "https://rpc-mainnet.blockchain.ki".to_string(),
                     chain_id: "kichain-2".to_string(),
+                    ..Default::default()
                 },
             ],
             wallet: vec![Wallet {
@@ -73,6 +211,8 @@ impl Config {
                 address: "toto".to_string(),
             }],
             slack_web_hook: None,
+            max_risk_score: None,
+            notifier: Vec::new(),
         })
     }
 
@@ -122,4 +262,22 @@ impl Config {
             bail!("unknown chain_id");
         }
     }
+
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        for wallet in self.wallet.iter() {
+            if !self
+                .chain
+                .iter()
+                .any(|c| c.validate_address(wallet.address.as_str()).is_ok())
+            {
+                bail!(
+                    "wallet {} address {} does not match any configured chain prefix",
+                    wallet.name,
+                    wallet.address
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
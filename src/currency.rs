@@ -0,0 +1,157 @@
+use crate::config::Chain;
+use crate::taurus::CurrencyInfo;
+use anyhow::{anyhow, bail};
+use std::collections::HashMap;
+
+// Cosmos denoms prefixed by their unit, e.g. uatom for 10^-6 ATOM
+const DENOM_UNITS: [(char, u32); 2] = [('u', 6), ('a', 18)];
+
+/// The currencies supported by Taurus, indexed by id, symbol and contract.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Currencies {
+    currencies: Vec<CurrencyInfo>,
+    by_id: HashMap<String, usize>,
+    by_symbol: HashMap<String, Vec<usize>>,
+    by_contract: HashMap<(String, String), usize>,
+}
+
+impl Currencies {
+    pub fn new(currencies: Vec<CurrencyInfo>) -> Self {
+        let mut by_id = HashMap::new();
+        let mut by_symbol: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut by_contract = HashMap::new();
+
+        for (i, currency) in currencies.iter().enumerate() {
+            by_id.insert(currency.id().to_string(), i);
+            by_symbol
+                .entry(currency.symbol().to_uppercase())
+                .or_default()
+                .push(i);
+            if let Some(contract) = currency.contract_address() {
+                by_contract.insert(contract_key(currency.blockchain(), contract), i);
+            }
+        }
+
+        Currencies {
+            currencies,
+            by_id,
+            by_symbol,
+            by_contract,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.currencies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.currencies.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, CurrencyInfo> {
+        self.currencies.iter()
+    }
+
+    pub fn by_id(&self, id: &str) -> Option<&CurrencyInfo> {
+        self.by_id.get(id).map(|&i| &self.currencies[i])
+    }
+
+    /// Currencies with `symbol` on any blockchain, ignoring case.
+    pub fn by_symbol(&self, symbol: &str) -> Vec<&CurrencyInfo> {
+        self.by_symbol
+            .get(&symbol.to_uppercase())
+            .into_iter()
+            .flatten()
+            .map(|&i| &self.currencies[i])
+            .collect()
+    }
+
+    pub fn by_contract(&self, blockchain: &str, contract_address: &str) -> Option<&CurrencyInfo> {
+        self.by_contract
+            .get(&contract_key(blockchain, contract_address))
+            .map(|&i| &self.currencies[i])
+    }
+
+    /// Looks a currency up by symbol and blockchain, `contract_address` being
+    /// `None` for the native currency of the blockchain.
+    pub fn get(
+        &self,
+        symbol: &str,
+        blockchain: &str,
+        contract_address: Option<&str>,
+    ) -> Option<&CurrencyInfo> {
+        match contract_address {
+            Some(contract) => self
+                .by_contract(blockchain, contract)
+                .filter(|c| c.symbol().eq_ignore_ascii_case(symbol)),
+            None => self.by_symbol(symbol).into_iter().find(|c| {
+                c.blockchain().eq_ignore_ascii_case(blockchain) && c.contract_address().is_none()
+            }),
+        }
+    }
+
+    /// The Taurus currency of the chain fee denom: `chain.currency_id` when
+    /// set, else the currency whose contract is the denom (IBC, CW20...) or
+    /// whose symbol and decimals match it, e.g. ATOM with 6 decimals for
+    /// `uatom`.
+    pub fn for_chain(&self, chain: &Chain) -> Result<&CurrencyInfo, anyhow::Error> {
+        if let Some(id) = &chain.currency_id {
+            return self
+                .by_id(id)
+                .ok_or_else(|| anyhow!("unknown taurus currency {} for {}", id, chain.chain_id));
+        }
+
+        let denom = chain.denom.as_str();
+        let mut candidates: Vec<&CurrencyInfo> = self
+            .iter()
+            .filter(|c| {
+                c.contract_address()
+                    .is_some_and(|contract| contract.eq_ignore_ascii_case(denom))
+            })
+            .collect();
+
+        if candidates.is_empty() && !denom.contains('/') {
+            candidates = self.by_symbol(denom);
+
+            let mut chars = denom.chars();
+            if let Some((_, decimals)) = chars
+                .next()
+                .and_then(|unit| DENOM_UNITS.iter().find(|(u, _)| *u == unit))
+            {
+                candidates.extend(
+                    self.by_symbol(chars.as_str())
+                        .into_iter()
+                        .filter(|c| c.decimals().is_ok_and(|d| d == *decimals)),
+                );
+            }
+            candidates.retain(|c| c.enabled() && c.contract_address().is_none());
+        }
+
+        match candidates.as_slice() {
+            [currency] => Ok(currency),
+            [] => bail!(
+                "no taurus currency for denom {} of {}",
+                denom,
+                chain.chain_id
+            ),
+            _ => bail!(
+                "ambiguous taurus currency for denom {} of {}, set currency_id",
+                denom,
+                chain.chain_id
+            ),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Currencies {
+    type Item = &'a CurrencyInfo;
+    type IntoIter = std::slice::Iter<'a, CurrencyInfo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+fn contract_key(blockchain: &str, contract_address: &str) -> (String, String) {
+    (blockchain.to_uppercase(), contract_address.to_lowercase())
+}
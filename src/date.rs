@@ -0,0 +1,59 @@
+use serde::{Deserialize, Deserializer};
+
+/// A Taurus date, parsed into a UTC timestamp with the `chrono` feature and
+/// kept as the raw string without it.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// Parses a Taurus date, RFC 3339 or naive UTC such as
+/// `"2022-06-01 12:34:56.789"`.
+#[cfg(feature = "chrono")]
+pub fn parse(date: &str) -> Result<Timestamp, anyhow::Error> {
+    use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+
+    let date = date.trim();
+
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(date) {
+        return Ok(parsed.with_timezone(&Utc));
+    }
+
+    for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(parsed) = NaiveDateTime::parse_from_str(date, format) {
+            return Ok(Utc.from_utc_datetime(&parsed));
+        }
+    }
+
+    anyhow::bail!("invalid date: {}", date)
+}
+
+#[cfg(not(feature = "chrono"))]
+pub fn parse(date: &str) -> Result<Timestamp, anyhow::Error> {
+    Ok(date.trim().to_string())
+}
+
+/// Formats a timestamp as Taurus expects it in queries.
+#[cfg(feature = "chrono")]
+pub fn format(date: &Timestamp) -> String {
+    date.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+#[cfg(not(feature = "chrono"))]
+pub fn format(date: &Timestamp) -> String {
+    date.clone()
+}
+
+/// Deserializes an optional date: null and empty dates are `None`, an
+/// unparseable one is an error.
+pub fn deserialize_option<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Timestamp>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(date) if !date.trim().is_empty() => parse(date.as_str())
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        _ => Ok(None),
+    }
+}
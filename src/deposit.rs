@@ -0,0 +1,129 @@
+use crate::date::Timestamp;
+use crate::taurus::{Taurus, Transaction, TransactionDirection, TransactionFilter};
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// What a `DepositWatcher` needs to resume after a restart without losing or
+/// repeating deposits: persist it after each poll.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct DepositCursor {
+    /// Highest transaction id already processed.
+    pub last_seen: Option<u64>,
+    /// Deposits seen while unconfirmed, not yielded yet.
+    #[serde(default)]
+    pub pending: BTreeSet<u64>,
+    /// Reception date polls start from, to avoid paging the whole history.
+    #[serde(default)]
+    pub since: Option<Timestamp>,
+}
+
+/// Polls Taurus for incoming transactions and yields each confirmed deposit
+/// once. Deposits seen while unconfirmed are kept pending until confirmed.
+pub struct DepositWatcher {
+    filter: TransactionFilter,
+    cursor: DepositCursor,
+}
+
+impl DepositWatcher {
+    /// `cursor` is the last `DepositWatcher::cursor` persisted, or the default
+    /// one to start from the beginning.
+    pub fn new(filter: TransactionFilter, cursor: DepositCursor) -> Self {
+        DepositWatcher {
+            filter: TransactionFilter {
+                direction: Some(TransactionDirection::Incoming),
+                ..filter
+            },
+            cursor,
+        }
+    }
+
+    pub fn cursor(&self) -> &DepositCursor {
+        &self.cursor
+    }
+
+    pub fn last_seen(&self) -> Option<u64> {
+        self.cursor.last_seen
+    }
+
+    pub fn pending(&self) -> &BTreeSet<u64> {
+        &self.cursor.pending
+    }
+
+    /// Fetches the transactions received since the oldest pending deposit, or
+    /// the last one seen, and processes them.
+    pub fn poll(&mut self, taurus: &Taurus) -> Result<Vec<Transaction>, anyhow::Error> {
+        let filter = match &self.cursor.since {
+            Some(since) => TransactionFilter {
+                from: Some(crate::date::format(since)),
+                ..self.filter.clone()
+            },
+            None => self.filter.clone(),
+        };
+        let transactions = taurus.all_transactions(&filter)?;
+
+        self.process(transactions)
+    }
+
+    /// Returns the newly confirmed incoming deposits out of `transactions`,
+    /// sorted by id.
+    pub fn process(
+        &mut self,
+        transactions: Vec<Transaction>,
+    ) -> Result<Vec<Transaction>, anyhow::Error> {
+        let mut transactions = transactions
+            .into_iter()
+            .filter(|t| t.is_incoming())
+            .map(|t| {
+                let id =
+                    t.id.parse::<u64>()
+                        .map_err(|_| anyhow!("invalid transaction id {}", t.id))?;
+                Ok((id, t))
+            })
+            .collect::<Result<Vec<(u64, Transaction)>, anyhow::Error>>()?;
+        transactions.sort_by_key(|(id, _)| *id);
+
+        let cursor = &mut self.cursor;
+        let mut deposits = Vec::new();
+        let mut pending_since: Option<Option<Timestamp>> = None;
+        let latest = transactions
+            .iter()
+            .filter_map(|(_, t)| t.reception_date.as_ref())
+            .max()
+            .cloned();
+
+        for (id, transaction) in transactions {
+            let is_new = cursor.last_seen.is_none_or(|last| id > last);
+            if !is_new && !cursor.pending.contains(&id) {
+                continue;
+            }
+
+            if transaction.is_confirmed() {
+                cursor.pending.remove(&id);
+                deposits.push(transaction);
+            } else {
+                cursor.pending.insert(id);
+                // an undated pending deposit forces polling from the start
+                pending_since = Some(match pending_since {
+                    None => transaction.reception_date,
+                    Some(since) => since.min(transaction.reception_date),
+                });
+            }
+
+            if is_new {
+                cursor.last_seen = Some(id);
+            }
+        }
+
+        match pending_since {
+            Some(since) => cursor.since = since,
+            None if cursor.pending.is_empty() && latest.is_some() => {
+                cursor.since = latest.max(cursor.since.take())
+            }
+            // pending deposits missing from this batch: keep the window
+            None => {}
+        }
+
+        Ok(deposits)
+    }
+}
@@ -0,0 +1,524 @@
+use crate::amount::Amount;
+use crate::taurus::{
+    ApproveParams, CallParams, ContractType, EthArgsParams, EthParams, ValueParams,
+};
+use anyhow::{anyhow, bail};
+use sha3::{Digest, Keccak256};
+use std::fmt;
+use std::str::FromStr;
+
+pub const ERC20_TRANSFER: &str = "transfer(address,uint256)";
+pub const ERC20_APPROVE: &str = "approve(address,uint256)";
+pub const ERC20_TRANSFER_FROM: &str = "transferFrom(address,address,uint256)";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AbiType {
+    Address,
+    Uint(usize),
+    Int(usize),
+    Bool,
+    Bytes,
+    FixedBytes(usize),
+    String,
+    Array(Box<AbiType>),
+    FixedArray(Box<AbiType>, usize),
+}
+
+impl AbiType {
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiType::Bytes | AbiType::String | AbiType::Array(_) => true,
+            AbiType::FixedArray(inner, _) => inner.is_dynamic(),
+            _ => false,
+        }
+    }
+
+    /// Whether whitespace around a value is insignificant: numbers,
+    /// addresses and bools, unlike strings whose spaces are kept.
+    pub fn is_trimmed(&self) -> bool {
+        matches!(
+            self,
+            AbiType::Address | AbiType::Uint(_) | AbiType::Int(_) | AbiType::Bool
+        )
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiType::Address => write!(f, "address"),
+            AbiType::Uint(size) => write!(f, "uint{}", size),
+            AbiType::Int(size) => write!(f, "int{}", size),
+            AbiType::Bool => write!(f, "bool"),
+            AbiType::Bytes => write!(f, "bytes"),
+            AbiType::FixedBytes(size) => write!(f, "bytes{}", size),
+            AbiType::String => write!(f, "string"),
+            AbiType::Array(inner) => write!(f, "{}[]", inner),
+            AbiType::FixedArray(inner, len) => write!(f, "{}[{}]", inner, len),
+        }
+    }
+}
+
+fn parse_int_size(size: &str, ty: &str) -> Result<usize, anyhow::Error> {
+    if size.is_empty() {
+        return Ok(256);
+    }
+
+    let size: usize = size.parse().map_err(|_| anyhow!("invalid type {}", ty))?;
+    if size == 0 || size > 256 || !size.is_multiple_of(8) {
+        bail!("invalid type {}", ty);
+    }
+    Ok(size)
+}
+
+impl FromStr for AbiType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(inner) = s.strip_suffix(']') {
+            let open = inner
+                .rfind('[')
+                .ok_or_else(|| anyhow!("invalid type {}", s))?;
+            let elem = AbiType::from_str(&inner[..open])?;
+            let len = &inner[open + 1..];
+
+            return if len.is_empty() {
+                Ok(AbiType::Array(Box::new(elem)))
+            } else {
+                let len = len.parse().map_err(|_| anyhow!("invalid type {}", s))?;
+                Ok(AbiType::FixedArray(Box::new(elem), len))
+            };
+        }
+
+        match s {
+            "address" => Ok(AbiType::Address),
+            "bool" => Ok(AbiType::Bool),
+            "bytes" => Ok(AbiType::Bytes),
+            "string" => Ok(AbiType::String),
+            _ => {
+                if let Some(size) = s.strip_prefix("uint") {
+                    Ok(AbiType::Uint(parse_int_size(size, s)?))
+                } else if let Some(size) = s.strip_prefix("int") {
+                    Ok(AbiType::Int(parse_int_size(size, s)?))
+                } else if let Some(size) = s.strip_prefix("bytes") {
+                    match size.parse::<usize>() {
+                        Ok(size) if (1..=32).contains(&size) => Ok(AbiType::FixedBytes(size)),
+                        _ => bail!("invalid type {}", s),
+                    }
+                } else {
+                    bail!("unsupported type {}", s)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FunctionSignature {
+    pub name: String,
+    pub inputs: Vec<AbiType>,
+}
+
+impl FunctionSignature {
+    pub fn selector(&self) -> [u8; 4] {
+        let hash = Keccak256::digest(self.to_string().as_bytes());
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+}
+
+impl fmt::Display for FunctionSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inputs: Vec<String> = self.inputs.iter().map(|i| i.to_string()).collect();
+        write!(f, "{}({})", self.name, inputs.join(","))
+    }
+}
+
+impl FromStr for FunctionSignature {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, inputs) = s
+            .strip_suffix(')')
+            .and_then(|s| s.split_once('('))
+            .ok_or_else(|| anyhow!("invalid function signature {}", s))?;
+
+        if name.is_empty()
+            || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            || name.starts_with(|c: char| c.is_ascii_digit())
+        {
+            bail!("invalid function name {}", name);
+        }
+        if inputs.contains('(') {
+            bail!("tuple arguments are not supported: {}", s);
+        }
+
+        let inputs = if inputs.trim().is_empty() {
+            Vec::new()
+        } else {
+            inputs
+                .split(',')
+                // allow named parameters, e.g. `transfer(address to, uint256 amount)`
+                .map(|i| AbiType::from_str(i.split_whitespace().next().unwrap_or_default()))
+                .collect::<Result<Vec<AbiType>, anyhow::Error>>()?
+        };
+
+        Ok(FunctionSignature {
+            name: name.to_string(),
+            inputs,
+        })
+    }
+}
+
+pub fn decode_hex(value: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let hex = value
+        .strip_prefix("0x")
+        .ok_or_else(|| anyhow!("hex value must start with 0x: {}", value))?;
+
+    // also keeps the slicing below on char boundaries
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("invalid hex: {}", value);
+    }
+    if !hex.len().is_multiple_of(2) {
+        bail!("odd length hex value: {}", value);
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| anyhow!("invalid hex: {}", value))
+        })
+        .collect()
+}
+
+pub fn encode_hex(data: &[u8]) -> String {
+    let hex: String = data.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("0x{}", hex)
+}
+
+fn parse_word(value: &str) -> Result<[u8; 32], anyhow::Error> {
+    let mut word = [0u8; 32];
+
+    if let Some(hex) = value.strip_prefix("0x") {
+        let bytes =
+            decode_hex(format!("0x{:0>width$}", hex, width = hex.len().div_ceil(2) * 2).as_str())?;
+        if bytes.len() > 32 {
+            bail!("value too large: {}", value);
+        }
+        word[32 - bytes.len()..].copy_from_slice(&bytes);
+        return Ok(word);
+    }
+
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+        bail!("invalid integer: {}", value);
+    }
+
+    for digit in value.bytes().map(|d| (d - b'0') as u16) {
+        let mut carry = digit;
+        for byte in word.iter_mut().rev() {
+            let v = (*byte as u16) * 10 + carry;
+            *byte = (v & 0xff) as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            bail!("value too large: {}", value);
+        }
+    }
+
+    Ok(word)
+}
+
+fn leading_zero_bits(word: &[u8; 32]) -> usize {
+    let mut bits = 0;
+    for byte in word.iter() {
+        if *byte == 0 {
+            bits += 8;
+        } else {
+            return bits + byte.leading_zeros() as usize;
+        }
+    }
+    bits
+}
+
+fn negate(word: &mut [u8; 32]) {
+    let mut carry = 1u16;
+    for byte in word.iter_mut().rev() {
+        let v = (!*byte) as u16 + carry;
+        *byte = (v & 0xff) as u8;
+        carry = v >> 8;
+    }
+}
+
+fn pad_right(data: &[u8]) -> Vec<u8> {
+    let mut out = data.to_vec();
+    out.resize(data.len().div_ceil(32) * 32, 0);
+    out
+}
+
+fn length_word(len: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(len as u64).to_be_bytes());
+    word
+}
+
+fn split_array(value: &str) -> Result<Vec<String>, anyhow::Error> {
+    let elems: Vec<serde_json::Value> = serde_json::from_str(value)
+        .map_err(|_| anyhow!("array values must be a JSON array: {}", value))?;
+
+    Ok(elems
+        .into_iter()
+        .map(|e| match e {
+            serde_json::Value::String(s) => s,
+            other => other.to_string(),
+        })
+        .collect())
+}
+
+/// Validates `value` against `ty` and returns its ABI encoding.
+pub fn encode_value(ty: &AbiType, value: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let value = if ty.is_trimmed() { value.trim() } else { value };
+
+    match ty {
+        AbiType::Address => {
+            let bytes = decode_hex(value)?;
+            if bytes.len() != 20 {
+                bail!("invalid address: {}", value);
+            }
+            let mut word = [0u8; 32];
+            word[12..].copy_from_slice(&bytes);
+            Ok(word.to_vec())
+        }
+        AbiType::Uint(size) => {
+            let word = parse_word(value)?;
+            if 256 - leading_zero_bits(&word) > *size {
+                bail!("value {} does not fit in {}", value, ty);
+            }
+            Ok(word.to_vec())
+        }
+        AbiType::Int(size) => {
+            let (negative, magnitude) = match value.strip_prefix('-') {
+                Some(magnitude) => (true, magnitude),
+                None => (false, value),
+            };
+            let mut word = parse_word(magnitude)?;
+            let bits = 256 - leading_zero_bits(&word);
+            // 2^(size-1) is only representable as a negative value
+            let is_min = bits == *size && word.iter().map(|b| b.count_ones()).sum::<u32>() == 1;
+            if bits > *size - 1 && !(negative && is_min) {
+                bail!("value {} does not fit in {}", value, ty);
+            }
+            if negative {
+                negate(&mut word);
+            }
+            Ok(word.to_vec())
+        }
+        AbiType::Bool => {
+            let mut word = [0u8; 32];
+            match value {
+                "true" => word[31] = 1,
+                "false" => {}
+                _ => bail!("invalid bool: {}", value),
+            }
+            Ok(word.to_vec())
+        }
+        AbiType::FixedBytes(size) => {
+            let bytes = decode_hex(value)?;
+            if bytes.len() != *size {
+                bail!("expected {} bytes for {}, got {}", size, ty, bytes.len());
+            }
+            Ok(pad_right(&bytes))
+        }
+        AbiType::Bytes => {
+            let bytes = decode_hex(value)?;
+            let mut out = length_word(bytes.len()).to_vec();
+            out.extend(pad_right(&bytes));
+            Ok(out)
+        }
+        AbiType::String => {
+            let mut out = length_word(value.len()).to_vec();
+            out.extend(pad_right(value.as_bytes()));
+            Ok(out)
+        }
+        AbiType::Array(inner) => {
+            let elems = split_array(value)?;
+            let types = vec![inner.as_ref().clone(); elems.len()];
+            let mut out = length_word(elems.len()).to_vec();
+            out.extend(encode_args(&types, &elems)?);
+            Ok(out)
+        }
+        AbiType::FixedArray(inner, len) => {
+            let elems = split_array(value)?;
+            if elems.len() != *len {
+                bail!("expected {} elements for {}, got {}", len, ty, elems.len());
+            }
+            let types = vec![inner.as_ref().clone(); elems.len()];
+            encode_args(&types, &elems)
+        }
+    }
+}
+
+/// ABI encodes a list of values as a tuple.
+pub fn encode_args(types: &[AbiType], values: &[String]) -> Result<Vec<u8>, anyhow::Error> {
+    if types.len() != values.len() {
+        bail!("expected {} values, got {}", types.len(), values.len());
+    }
+
+    let encoded = types
+        .iter()
+        .zip(values.iter())
+        .map(|(ty, value)| encode_value(ty, value))
+        .collect::<Result<Vec<Vec<u8>>, anyhow::Error>>()?;
+
+    let head_size: usize = types
+        .iter()
+        .zip(encoded.iter())
+        .map(|(ty, enc)| if ty.is_dynamic() { 32 } else { enc.len() })
+        .sum();
+
+    let mut head = Vec::new();
+    let mut tail = Vec::new();
+    for (ty, enc) in types.iter().zip(encoded) {
+        if ty.is_dynamic() {
+            head.extend_from_slice(&length_word(head_size + tail.len()));
+            tail.extend(enc);
+        } else {
+            head.extend(enc);
+        }
+    }
+
+    head.extend(tail);
+    Ok(head)
+}
+
+/// Builds a contract call for Taurus from a Solidity function signature and
+/// typed arguments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EthCall {
+    blockchain: String,
+    signature: FunctionSignature,
+    args: Vec<(String, String)>,
+}
+
+impl EthCall {
+    pub fn new(blockchain: &str, signature: &str) -> Result<Self, anyhow::Error> {
+        Ok(EthCall {
+            blockchain: blockchain.to_string(),
+            signature: FunctionSignature::from_str(signature)?,
+            args: Vec::new(),
+        })
+    }
+
+    pub fn signature(&self) -> &FunctionSignature {
+        &self.signature
+    }
+
+    /// Adds the next argument, validated against the signature.
+    pub fn arg(mut self, name: &str, value: &str) -> Result<Self, anyhow::Error> {
+        let ty = self
+            .signature
+            .inputs
+            .get(self.args.len())
+            .ok_or_else(|| anyhow!("too many arguments for {}", self.signature))?;
+
+        let value = if ty.is_trimmed() { value.trim() } else { value };
+        encode_value(ty, value).map_err(|e| anyhow!("argument {}: {}", name, e))?;
+
+        self.args.push((name.to_string(), value.to_string()));
+        Ok(self)
+    }
+
+    fn check_complete(&self) -> Result<(), anyhow::Error> {
+        if self.args.len() != self.signature.inputs.len() {
+            bail!(
+                "{} expects {} arguments, got {}",
+                self.signature,
+                self.signature.inputs.len(),
+                self.args.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// Calldata: function selector followed by the ABI encoded arguments.
+    pub fn encode(&self) -> Result<Vec<u8>, anyhow::Error> {
+        self.check_complete()?;
+
+        let values: Vec<String> = self.args.iter().map(|(_, v)| v.clone()).collect();
+        let mut out = self.signature.selector().to_vec();
+        out.extend(encode_args(&self.signature.inputs, &values)?);
+        Ok(out)
+    }
+
+    pub fn build(&self) -> Result<CallParams, anyhow::Error> {
+        self.check_complete()?;
+
+        Ok(CallParams {
+            blockchain: self.blockchain.clone(),
+            eth: EthParams {
+                function_signature: self.signature.to_string(),
+                args: self
+                    .signature
+                    .inputs
+                    .iter()
+                    .zip(self.args.iter())
+                    .map(|(ty, (name, value))| EthArgsParams {
+                        name: name.clone(),
+                        attribute_type: ty.to_string(),
+                        value: ValueParams {
+                            primitive: value.clone(),
+                        },
+                    })
+                    .collect(),
+            },
+        })
+    }
+
+    /// Parameters for `Taurus::contract_call`.
+    pub fn build_request(
+        &self,
+        from_address_id: &str,
+        to_whitelisted_address_id: &str,
+        contract_type: ContractType,
+    ) -> Result<ApproveParams, anyhow::Error> {
+        Ok(ApproveParams {
+            from_address_id: from_address_id.to_string(),
+            to_whitelisted_address_id: to_whitelisted_address_id.to_string(),
+            contract_type: contract_type.to_string(),
+            call: self.build()?,
+        })
+    }
+
+    pub fn erc20_transfer(
+        blockchain: &str,
+        to: &str,
+        amount: &Amount,
+    ) -> Result<Self, anyhow::Error> {
+        EthCall::new(blockchain, ERC20_TRANSFER)?
+            .arg("to", to)?
+            .arg("amount", amount.value.to_string().as_str())
+    }
+
+    pub fn erc20_approve(
+        blockchain: &str,
+        spender: &str,
+        amount: &Amount,
+    ) -> Result<Self, anyhow::Error> {
+        EthCall::new(blockchain, ERC20_APPROVE)?
+            .arg("spender", spender)?
+            .arg("amount", amount.value.to_string().as_str())
+    }
+
+    pub fn erc20_transfer_from(
+        blockchain: &str,
+        from: &str,
+        to: &str,
+        amount: &Amount,
+    ) -> Result<Self, anyhow::Error> {
+        EthCall::new(blockchain, ERC20_TRANSFER_FROM)?
+            .arg("from", from)?
+            .arg("to", to)?
+            .arg("amount", amount.value.to_string().as_str())
+    }
+}
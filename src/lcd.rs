@@ -0,0 +1,178 @@
+use crate::config::Chain;
+use crate::taurus::AccountInfo;
+use anyhow::bail;
+use base64::engine::general_purpose;
+use base64::Engine;
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::*;
+use std::time::Duration;
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct BaseAccount {
+    pub address: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub account_number: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub sequence: u64,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct BaseVestingAccount {
+    pub base_account: BaseAccount,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct VestingAccount {
+    pub base_vesting_account: BaseVestingAccount,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ModuleAccount {
+    pub base_account: BaseAccount,
+    pub name: String,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(tag = "@type")]
+pub enum Account {
+    #[serde(rename = "/cosmos.auth.v1beta1.BaseAccount")]
+    Base(BaseAccount),
+    #[serde(rename = "/cosmos.auth.v1beta1.ModuleAccount")]
+    Module(ModuleAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.ContinuousVestingAccount")]
+    ContinuousVesting(VestingAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.DelayedVestingAccount")]
+    DelayedVesting(VestingAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.PeriodicVestingAccount")]
+    PeriodicVesting(VestingAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.PermanentLockedAccount")]
+    PermanentLocked(VestingAccount),
+}
+
+impl Account {
+    pub fn base_account(&self) -> &BaseAccount {
+        match self {
+            Account::Base(base) => base,
+            Account::Module(module) => &module.base_account,
+            Account::ContinuousVesting(vesting)
+            | Account::DelayedVesting(vesting)
+            | Account::PeriodicVesting(vesting)
+            | Account::PermanentLocked(vesting) => &vesting.base_vesting_account.base_account,
+        }
+    }
+}
+
+impl From<&BaseAccount> for AccountInfo {
+    fn from(account: &BaseAccount) -> Self {
+        AccountInfo {
+            sequence: account.sequence.to_string(),
+            account_number: account.account_number.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AccountResponse {
+    pub account: Account,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GasInfo {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub gas_wanted: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub gas_used: u64,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SimulateResponse {
+    pub gas_info: GasInfo,
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+struct SimulateParams {
+    tx_bytes: String,
+}
+
+pub struct Lcd {
+    address: String,
+    client: Client,
+}
+
+impl Lcd {
+    pub fn new(chain: &Chain) -> Result<Self, anyhow::Error> {
+        let client = Client::builder().timeout(Duration::from_secs(30)).build()?;
+
+        Ok(Lcd {
+            address: chain.lcd.trim_end_matches('/').to_string(),
+            client,
+        })
+    }
+
+    fn get<T: serde::de::DeserializeOwned>(&self, endpoint: &str) -> Result<T, anyhow::Error> {
+        log::debug!("LCD GET {}", endpoint);
+        let request = self
+            .client
+            .get(format!("{}{}", self.address, endpoint))
+            .send()?
+            .error_for_status()?;
+
+        let data = &request.text()?;
+        log::trace!("-> payload\n{}", data);
+
+        Ok(serde_json::from_str::<T>(data)?)
+    }
+
+    fn post<T: serde::de::DeserializeOwned, U: serde::ser::Serialize>(
+        &self,
+        endpoint: &str,
+        data: &U,
+    ) -> Result<T, anyhow::Error> {
+        log::debug!("LCD POST {}", endpoint);
+        let request = self
+            .client
+            .post(format!("{}{}", self.address, endpoint))
+            .json(data)
+            .send()?;
+
+        let status = request.status();
+        let data = &request.text()?;
+        log::trace!("-> payload\n{}", data);
+
+        if !status.is_success() {
+            bail!("LCD {} returned {}: {}", endpoint, status, data);
+        }
+
+        Ok(serde_json::from_str::<T>(data)?)
+    }
+
+    pub fn account(&self, address: &str) -> Result<Account, anyhow::Error> {
+        let response: AccountResponse =
+            self.get(format!("/cosmos/auth/v1beta1/accounts/{}", address).as_str())?;
+
+        Ok(response.account)
+    }
+
+    pub fn account_info(&self, address: &str) -> Result<AccountInfo, anyhow::Error> {
+        Ok(self.account(address)?.base_account().into())
+    }
+
+    /// Fetches `AccountInfo` for each signer, in the order expected by
+    /// `RequestParams.accounts_info`.
+    pub fn accounts_info(&self, signers: &[String]) -> Result<Vec<AccountInfo>, anyhow::Error> {
+        signers
+            .iter()
+            .map(|address| self.account_info(address.as_str()))
+            .collect()
+    }
+
+    pub fn simulate(&self, tx_bytes: &[u8]) -> Result<SimulateResponse, anyhow::Error> {
+        self.post(
+            "/cosmos/tx/v1beta1/simulate",
+            &SimulateParams {
+                tx_bytes: general_purpose::STANDARD.encode(tx_bytes),
+            },
+        )
+    }
+}
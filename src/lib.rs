@@ -1,5 +1,21 @@
+pub mod address;
+pub mod amount;
+#[cfg(feature = "chrono")]
+pub mod audit;
+pub mod auth;
+pub mod coin;
 pub mod config;
+pub mod currency;
+pub mod date;
+pub mod deposit;
+pub mod eth;
+pub mod lcd;
+pub mod notify;
 pub mod payload;
 mod proto;
+pub mod request;
+pub mod rpc;
+pub mod simulate;
 pub mod taurus;
 mod test;
+pub mod tx;
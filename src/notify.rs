@@ -0,0 +1,288 @@
+use crate::config::{Config, NotifierConfig};
+use crate::taurus::{RequestInfos, RequestStatus};
+use anyhow::anyhow;
+use reqwest::blocking::Client;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Request and whitelist lifecycle events worth telling a human about.
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    RequestCreated {
+        id: String,
+        request_type: String,
+        currency: String,
+    },
+    ApprovalNeeded {
+        id: String,
+        request_type: String,
+        currency: String,
+    },
+    RequestConfirmed {
+        id: String,
+    },
+    RequestRejected {
+        id: String,
+        status: String,
+    },
+    WhitelistPending {
+        id: String,
+        address: String,
+    },
+}
+
+impl Event {
+    /// Events for a request Taurus just created: created, and approval needed
+    /// while it waits for approvers.
+    pub fn for_new_request(request: &RequestInfos) -> Vec<Event> {
+        let mut events = vec![Event::RequestCreated {
+            id: request.id.clone(),
+            request_type: request.type_request.clone(),
+            currency: request.currency.clone(),
+        }];
+
+        if matches!(
+            request.request_status(),
+            RequestStatus::Created | RequestStatus::Approving
+        ) {
+            events.push(Event::ApprovalNeeded {
+                id: request.id.clone(),
+                request_type: request.type_request.clone(),
+                currency: request.currency.clone(),
+            });
+        }
+
+        events
+    }
+
+    /// Event for a request that reached a final status, if any.
+    pub fn for_final_request(request: &RequestInfos) -> Option<Event> {
+        let status = request.request_status();
+
+        if status.is_success() {
+            Some(Event::RequestConfirmed {
+                id: request.id.clone(),
+            })
+        } else if status.is_final() {
+            Some(Event::RequestRejected {
+                id: request.id.clone(),
+                status: request.status.clone(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::RequestCreated {
+                id,
+                request_type,
+                currency,
+            } => write!(
+                f,
+                ":outbox_tray: Request {} created ({} {})",
+                id, request_type, currency
+            ),
+            Event::ApprovalNeeded {
+                id,
+                request_type,
+                currency,
+            } => write!(
+                f,
+                ":warning: Request {} needs approval ({} {})",
+                id, request_type, currency
+            ),
+            Event::RequestConfirmed { id } => {
+                write!(f, ":white_check_mark: Request {} confirmed", id)
+            }
+            Event::RequestRejected { id, status } => {
+                write!(f, ":x: Request {} ended as {}", id, status)
+            }
+            Event::WhitelistPending { id, address } => write!(
+                f,
+                ":hourglass: Whitelist {} for {} is pending approval",
+                id, address
+            ),
+        }
+    }
+}
+
+/// A channel events are sent to.
+pub trait Notifier {
+    fn send(&self, event: &Event) -> Result<(), anyhow::Error>;
+}
+
+fn client() -> Result<Client, anyhow::Error> {
+    Ok(Client::builder().timeout(Duration::from_secs(10)).build()?)
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+struct SlackMessage {
+    text: String,
+}
+
+pub struct SlackNotifier {
+    web_hook: String,
+    client: Client,
+}
+
+impl SlackNotifier {
+    pub fn new(web_hook: &str) -> Result<Self, anyhow::Error> {
+        Ok(SlackNotifier {
+            web_hook: web_hook.to_string(),
+            client: client()?,
+        })
+    }
+}
+
+impl Notifier for SlackNotifier {
+    fn send(&self, event: &Event) -> Result<(), anyhow::Error> {
+        log::debug!("slack notification: {}", event);
+
+        self.client
+            .post(self.web_hook.as_str())
+            .json(&SlackMessage {
+                text: event.to_string(),
+            })
+            .send()?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+struct WebhookMessage<'a> {
+    #[serde(flatten)]
+    event: &'a Event,
+    text: String,
+}
+
+/// Posts events as JSON, e.g.
+/// `{"event":"request_confirmed","id":"42","text":"..."}`.
+pub struct WebhookNotifier {
+    url: String,
+    headers: BTreeMap<String, String>,
+    client: Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: &str, headers: BTreeMap<String, String>) -> Result<Self, anyhow::Error> {
+        Ok(WebhookNotifier {
+            url: url.to_string(),
+            headers,
+            client: client()?,
+        })
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn send(&self, event: &Event) -> Result<(), anyhow::Error> {
+        log::debug!("webhook notification: {}", event);
+
+        let mut request_builder = self.client.post(self.url.as_str()).json(&WebhookMessage {
+            event,
+            text: event.to_string(),
+        });
+        for (name, value) in &self.headers {
+            request_builder = request_builder.header(name, value);
+        }
+
+        request_builder.send()?.error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Only writes events to the log.
+pub struct LogNotifier {
+    level: log::Level,
+}
+
+impl LogNotifier {
+    pub fn new(level: log::Level) -> Self {
+        LogNotifier { level }
+    }
+}
+
+impl Default for LogNotifier {
+    fn default() -> Self {
+        LogNotifier::new(log::Level::Info)
+    }
+}
+
+impl Notifier for LogNotifier {
+    fn send(&self, event: &Event) -> Result<(), anyhow::Error> {
+        log::log!(self.level, "{}", event);
+
+        Ok(())
+    }
+}
+
+/// Fans events out to every configured channel.
+#[derive(Default)]
+pub struct Notifiers(Vec<Box<dyn Notifier + Send + Sync>>);
+
+impl Notifiers {
+    pub fn new() -> Self {
+        Notifiers::default()
+    }
+
+    /// Channels listed under `notifier` in the config, plus Slack when
+    /// `slack_web_hook` is set.
+    pub fn from_config(config: &Config) -> Result<Self, anyhow::Error> {
+        let mut notifiers = Notifiers::new();
+
+        if let Some(web_hook) = config.slack_web_hook.as_deref().filter(|h| !h.is_empty()) {
+            notifiers.add(SlackNotifier::new(web_hook)?);
+        }
+
+        for notifier in &config.notifier {
+            match notifier {
+                NotifierConfig::Slack { web_hook } => notifiers.add(SlackNotifier::new(web_hook)?),
+                NotifierConfig::Webhook { url, headers } => {
+                    notifiers.add(WebhookNotifier::new(url, headers.clone())?)
+                }
+                NotifierConfig::Log { level } => {
+                    let level = match level {
+                        Some(level) => log::Level::from_str(level)
+                            .map_err(|_| anyhow!("invalid log level {}", level))?,
+                        None => log::Level::Info,
+                    };
+                    notifiers.add(LogNotifier::new(level))
+                }
+            }
+        }
+
+        Ok(notifiers)
+    }
+
+    pub fn add<N: Notifier + Send + Sync + 'static>(&mut self, notifier: N) {
+        self.0.push(Box::new(notifier));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Sends `event` to every channel, only logging failures: a notification
+    /// must never make the operation it reports on fail.
+    pub fn notify(&self, event: &Event) {
+        for notifier in &self.0 {
+            if let Err(e) = notifier.send(event) {
+                log::warn!("failed to send notification: {}", e);
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 extern crate quick_protobuf;
 
+pub use crate::coin::{Coin, Coins};
 use base64::engine::general_purpose;
 use base64::Engine;
 use quick_protobuf::Writer;
@@ -50,11 +51,6 @@ pub struct IntObject {
     pub int: String,
 }
 
-pub struct Coin {
-    pub denom: String,
-    pub amount: String,
-}
-
 #[derive(Serialize, Clone, Debug, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum Objects {
@@ -95,6 +91,43 @@ impl Message {
         general_purpose::STANDARD.encode(out2)
     }
 
+    fn build_coin_object(coin: &Coin) -> Objects {
+        Objects::Message(MessageObject {
+            message: vec![
+                MesssageDetails {
+                    kind: KIND_STRING.to_string(),
+                    name: "denom".to_string(),
+                    field_id: 1,
+                    value: Box::new(Objects::String(StringObject {
+                        string: coin.denom.clone(),
+                    })),
+                },
+                MesssageDetails {
+                    kind: KIND_STRING.to_string(),
+                    name: "amount".to_string(),
+                    field_id: 2,
+                    value: Box::new(Objects::String(StringObject {
+                        string: coin.amount.to_string(),
+                    })),
+                },
+            ],
+        })
+    }
+
+    fn build_coins_details(name: &str, field_id: u32, coins: &Coins) -> MesssageDetails {
+        MesssageDetails {
+            kind: KIND_ARRAY.to_string(),
+            name: name.to_string(),
+            field_id,
+            value: Box::new(Objects::Array(ArrayObject {
+                array: ArrayDetail {
+                    kind: KIND_MESSAGE.to_string(),
+                    elems: coins.iter().map(Self::build_coin_object).collect(),
+                },
+            })),
+        }
+    }
+
     pub fn build_authz_message(
         granter: String,
         grantee: String,
@@ -211,6 +244,29 @@ impl Message {
         }
     }
 
+    pub fn build_bank_send(from_address: String, to_address: String, amount: Coins) -> Message {
+        Message {
+            url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            message: vec![
+                MesssageDetails {
+                    kind: KIND_STRING.to_string(),
+                    name: "from_address".to_string(),
+                    field_id: 1,
+                    value: Box::new(Objects::String(StringObject {
+                        string: from_address,
+                    })),
+                },
+                MesssageDetails {
+                    kind: KIND_STRING.to_string(),
+                    name: "to_address".to_string(),
+                    field_id: 2,
+                    value: Box::new(Objects::String(StringObject { string: to_address })),
+                },
+                Self::build_coins_details("amount", 3, &amount),
+            ],
+        }
+    }
+
     pub fn build_contract_migrate(
         sender: String,
         contract: String,
@@ -254,7 +310,7 @@ impl Message {
         sender: String,
         contract: String,
         msg: String,
-        funds: Vec<Coin>,
+        funds: Coins,
     ) -> Message {
         // if funds is empty, then it will be ignored
         let mut message = Message {
@@ -283,43 +339,9 @@ impl Message {
 
         if !funds.is_empty() {
             // append funds
-            let funds = MesssageDetails {
-                kind: KIND_ARRAY.to_string(),
-                name: "funds".to_string(),
-                field_id: 5,
-                value: Box::new(Objects::Array(ArrayObject {
-                    array: ArrayDetail {
-                        kind: KIND_MESSAGE.to_string(),
-                        elems: funds
-                            .iter()
-                            .map(|coin| {
-                                Objects::Message(MessageObject {
-                                    message: vec![
-                                        MesssageDetails {
-                                            kind: KIND_STRING.to_string(),
-                                            name: "denom".to_string(),
-                                            field_id: 1,
-                                            value: Box::new(Objects::String(StringObject {
-                                                string: coin.denom.clone(),
-                                            })),
-                                        },
-                                        MesssageDetails {
-                                            kind: KIND_STRING.to_string(),
-                                            name: "amount".to_string(),
-                                            field_id: 2,
-                                            value: Box::new(Objects::String(StringObject {
-                                                string: coin.amount.clone(),
-                                            })),
-                                        },
-                                    ],
-                                })
-                            })
-                            .collect(),
-                    },
-                })),
-            };
-
-            message.message.push(funds);
+            message
+                .message
+                .push(Self::build_coins_details("funds", 5, &funds));
         }
 
         message
@@ -0,0 +1,177 @@
+use crate::coin::Coin;
+use crate::config::{Chain, Config, Wallet};
+use crate::lcd::Lcd;
+use crate::payload::Message;
+use crate::taurus::{BroadcastKind, RequestParams, RequestResponse, Taurus};
+use anyhow::{anyhow, bail};
+
+/// Assembles a `RequestParams` from the configuration, resolving signers and
+/// their on-chain account info before anything is sent to Taurus.
+pub struct RequestBuilder<'a> {
+    config: &'a Config,
+    chain: Chain,
+    signers: Vec<String>,
+    messages: Vec<Message>,
+    broadcast_kind: BroadcastKind,
+    gas_limit: Option<u64>,
+    fee: Option<u128>,
+    fee_denom: String,
+}
+
+impl<'a> RequestBuilder<'a> {
+    pub fn for_chain(config: &'a Config, chain_id: &str) -> Result<Self, anyhow::Error> {
+        let chain = config.find_chain(&chain_id.to_string())?;
+
+        Ok(RequestBuilder {
+            config,
+            signers: Vec::new(),
+            messages: Vec::new(),
+            broadcast_kind: chain.broadcast_kind.unwrap_or_default(),
+            gas_limit: chain.default_gas_limit,
+            fee: None,
+            fee_denom: chain.denom.clone(),
+            chain,
+        })
+    }
+
+    pub fn chain(&self) -> &Chain {
+        &self.chain
+    }
+
+    /// Adds a signer by its wallet name in the configuration.
+    pub fn signer(mut self, wallet_name: &str) -> Self {
+        self.signers.push(wallet_name.to_string());
+        self
+    }
+
+    pub fn message(mut self, message: Message) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    pub fn messages(mut self, messages: Vec<Message>) -> Self {
+        self.messages.extend(messages);
+        self
+    }
+
+    pub fn broadcast_kind(mut self, broadcast_kind: BroadcastKind) -> Self {
+        self.broadcast_kind = broadcast_kind;
+        self
+    }
+
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Sets the fee, in base units of the fee denom. When unset, the fee is
+    /// derived from the chain gas price and the gas limit.
+    pub fn fee(mut self, fee: u128) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    pub fn fee_denom(mut self, denom: &str) -> Self {
+        self.fee_denom = denom.to_string();
+        self
+    }
+
+    fn resolve_fee(&self) -> Result<Coin, anyhow::Error> {
+        match (self.fee, self.gas_limit) {
+            (Some(fee), _) => Coin::new(fee, self.fee_denom.as_str()),
+            (None, Some(gas_limit)) if self.fee_denom == self.chain.denom => {
+                crate::simulate::fee_for_gas(&self.chain, gas_limit)
+            }
+            _ => bail!("request has no fee"),
+        }
+    }
+
+    /// Checks everything that can be checked without network access and
+    /// returns the resolved signer wallets.
+    pub fn validate(&self) -> Result<Vec<Wallet>, anyhow::Error> {
+        if self.messages.is_empty() {
+            bail!("request has no message");
+        }
+        match self.gas_limit {
+            None => bail!("request has no gas limit"),
+            Some(0) => bail!("gas limit must be positive"),
+            _ => {}
+        }
+        self.chain.check_fee(&self.resolve_fee()?)?;
+
+        self.validate_signers()
+    }
+
+    fn validate_signers(&self) -> Result<Vec<Wallet>, anyhow::Error> {
+        if self.signers.is_empty() {
+            bail!("request has no signer");
+        }
+
+        self.signers
+            .iter()
+            .map(|name| {
+                let wallet = self.config.find_wallet(name)?;
+                self.chain.validate_address(wallet.address.as_str())?;
+                Ok(wallet)
+            })
+            .collect()
+    }
+
+    /// Simulates the messages on the chain and sets gas limit and fee from the
+    /// result.
+    /// `gas_adjustment` defaults to the chain setting.
+    pub fn simulate(mut self, gas_adjustment: Option<f64>) -> Result<Self, anyhow::Error> {
+        let addresses: Vec<String> = self
+            .validate_signers()?
+            .into_iter()
+            .map(|w| w.address)
+            .collect();
+
+        let gas_adjustment = gas_adjustment.unwrap_or_else(|| self.chain.gas_adjustment());
+        let estimate =
+            crate::simulate::estimate(&self.chain, &self.messages, &addresses, gas_adjustment)?;
+
+        self.gas_limit = Some(estimate.gas_limit);
+        self.fee = Some(estimate.fee.amount);
+        self.fee_denom = estimate.fee.denom;
+        Ok(self)
+    }
+
+    pub fn build(self, taurus: &Taurus) -> Result<RequestParams, anyhow::Error> {
+        let wallets = self.validate()?;
+        let fee = self.resolve_fee()?;
+
+        let signers = wallets
+            .iter()
+            .map(|wallet| {
+                let address = taurus.addresses_by_address(wallet.clone())?;
+                address
+                    .id
+                    .parse::<u16>()
+                    .map_err(|_| anyhow!("invalid taurus address id {}", address.id))
+            })
+            .collect::<Result<Vec<u16>, anyhow::Error>>()?;
+
+        let addresses: Vec<String> = wallets.into_iter().map(|w| w.address).collect();
+        let accounts_info = Lcd::new(&self.chain)?.accounts_info(&addresses)?;
+
+        Ok(RequestParams {
+            chain_id: self.chain.chain_id,
+            signers,
+            broadcast_kind: self
+                .broadcast_kind
+                .for_sdk_version(self.chain.sdk_version.as_deref()),
+            fee_denom: fee.denom,
+            gas_limit: self.gas_limit.unwrap_or_default().to_string(),
+            fee: fee.amount.to_string(),
+            accounts_info,
+            messages: self.messages,
+        })
+    }
+
+    pub fn submit(self, taurus: &Taurus) -> Result<RequestResponse, anyhow::Error> {
+        let params = self.build(taurus)?;
+
+        taurus.request(params)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::config::Chain;
+use crate::taurus::Taurus;
+use anyhow::bail;
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use serde_aux::prelude::*;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RpcResponse<T> {
+    pub result: Option<T>,
+    pub error: Option<RpcError>,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct DeliverTx {
+    #[serde(default)]
+    pub code: u32,
+    #[serde(default)]
+    pub codespace: String,
+    #[serde(default)]
+    pub log: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub gas_wanted: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub gas_used: u64,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TxResponse {
+    pub hash: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub height: u64,
+    pub tx_result: DeliverTx,
+}
+
+/// Outcome of a transaction once included in a block.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TxResult {
+    pub hash: String,
+    pub height: u64,
+    pub code: u32,
+    pub codespace: String,
+    pub gas_wanted: u64,
+    pub gas_used: u64,
+    pub raw_log: String,
+}
+
+impl TxResult {
+    pub fn is_success(&self) -> bool {
+        self.code == 0
+    }
+}
+
+impl From<TxResponse> for TxResult {
+    fn from(tx: TxResponse) -> Self {
+        TxResult {
+            hash: tx.hash,
+            height: tx.height,
+            code: tx.tx_result.code,
+            codespace: tx.tx_result.codespace,
+            gas_wanted: tx.tx_result.gas_wanted,
+            gas_used: tx.tx_result.gas_used,
+            raw_log: tx.tx_result.log,
+        }
+    }
+}
+
+pub fn normalize_hash(hash: &str) -> Result<String, anyhow::Error> {
+    let hash = hash.trim_start_matches("0x").trim_start_matches("0X");
+
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("invalid tx hash: {}", hash);
+    }
+
+    Ok(hash.to_uppercase())
+}
+
+pub struct Rpc {
+    address: String,
+    client: Client,
+}
+
+impl Rpc {
+    pub fn new(chain: &Chain) -> Result<Self, anyhow::Error> {
+        let client = Client::builder().timeout(Duration::from_secs(30)).build()?;
+
+        Ok(Rpc {
+            address: chain.rpc.trim_end_matches('/').to_string(),
+            client,
+        })
+    }
+
+    fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+    ) -> Result<RpcResponse<T>, anyhow::Error> {
+        log::debug!("RPC GET {}", endpoint);
+        let request = self
+            .client
+            .get(format!("{}{}", self.address, endpoint))
+            .send()?;
+
+        let data = &request.text()?;
+        log::trace!("-> payload\n{}", data);
+
+        Ok(serde_json::from_str::<RpcResponse<T>>(data)?)
+    }
+
+    /// Looks a transaction up by hash, `None` while it is not in a block yet.
+    pub fn tx(&self, hash: &str) -> Result<Option<TxResult>, anyhow::Error> {
+        let hash = normalize_hash(hash)?;
+        let response: RpcResponse<TxResponse> =
+            self.get(format!("/tx?hash=0x{}", hash).as_str())?;
+
+        match (response.result, response.error) {
+            (Some(tx), _) => Ok(Some(tx.into())),
+            (None, Some(error)) => {
+                let data = error.data.unwrap_or_default();
+                if data.contains("not found") {
+                    Ok(None)
+                } else {
+                    bail!("rpc error {}: {} {}", error.code, error.message, data)
+                }
+            }
+            (None, None) => bail!("empty rpc response for tx {}", hash),
+        }
+    }
+
+    pub fn wait_for_tx(&self, hash: &str, timeout: Duration) -> Result<TxResult, anyhow::Error> {
+        let start = Instant::now();
+
+        loop {
+            if let Some(tx) = self.tx(hash)? {
+                return Ok(tx);
+            }
+
+            if start.elapsed() >= timeout {
+                bail!("tx {} not included after {:?}", hash, timeout);
+            }
+
+            sleep(POLL_INTERVAL.min(timeout.saturating_sub(start.elapsed())));
+        }
+    }
+}
+
+/// Fetches a Taurus request and waits until each of its transactions is
+/// included on `chain`.
+pub fn confirm_request(
+    taurus: &Taurus,
+    chain: &Chain,
+    request_id: u64,
+    timeout: Duration,
+) -> Result<Vec<TxResult>, anyhow::Error> {
+    let request = taurus.request_by_id(request_id)?.result;
+    let hashes = request.tx_hashes();
+
+    if hashes.is_empty() {
+        bail!(
+            "request {} has no broadcast tx (status {})",
+            request_id,
+            request.status
+        );
+    }
+
+    let rpc = Rpc::new(chain)?;
+    let start = Instant::now();
+
+    hashes
+        .iter()
+        .map(|hash| rpc.wait_for_tx(hash, timeout.saturating_sub(start.elapsed())))
+        .collect()
+}
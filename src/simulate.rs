@@ -0,0 +1,80 @@
+use crate::amount::Amount;
+use crate::coin::Coin;
+use crate::config::Chain;
+use crate::lcd::Lcd;
+use crate::payload::Message;
+use anyhow::{anyhow, bail};
+
+pub const DEFAULT_GAS_ADJUSTMENT: f64 = 1.3;
+
+// the gas limit of a simulated tx is ignored by the chain
+const SIMULATION_GAS_LIMIT: u64 = 0;
+const GAS_PRICE_DECIMALS: u32 = 18;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasEstimate {
+    pub gas_used: u64,
+    pub gas_limit: u64,
+    pub fee: Coin,
+}
+
+pub fn adjust_gas(gas_used: u64, gas_adjustment: f64) -> Result<u64, anyhow::Error> {
+    if !gas_adjustment.is_finite() || gas_adjustment < 1.0 {
+        bail!("invalid gas adjustment: {}", gas_adjustment);
+    }
+
+    Ok((gas_used as f64 * gas_adjustment).ceil() as u64)
+}
+
+/// Computes `ceil(gas_limit * chain.gas_price)` in the chain denom.
+pub fn fee_for_gas(chain: &Chain, gas_limit: u64) -> Result<Coin, anyhow::Error> {
+    let gas_price = chain
+        .gas_price
+        .as_ref()
+        .ok_or_else(|| anyhow!("no gas price configured for {}", chain.chain_id))?;
+    let gas_price = Amount::from_decimal_str(gas_price, GAS_PRICE_DECIMALS, &chain.denom)?;
+
+    let scale = 10u128.pow(GAS_PRICE_DECIMALS);
+    let fee = (gas_limit as u128)
+        .checked_mul(gas_price.value)
+        .ok_or_else(|| anyhow!("fee overflow"))?;
+
+    Coin::new(fee.div_ceil(scale), chain.denom.as_str())
+}
+
+pub fn simulate(
+    chain: &Chain,
+    messages: &[Message],
+    signers: &[String],
+) -> Result<u64, anyhow::Error> {
+    let lcd = Lcd::new(chain)?;
+
+    let sequences = signers
+        .iter()
+        .map(|address| Ok(lcd.account(address)?.base_account().sequence))
+        .collect::<Result<Vec<u64>, anyhow::Error>>()?;
+    let tx = crate::tx::encode_unsigned_tx(messages, &sequences, SIMULATION_GAS_LIMIT)?;
+
+    let response = lcd.simulate(&tx)?;
+    log::debug!("simulation used {} gas", response.gas_info.gas_used);
+
+    Ok(response.gas_info.gas_used)
+}
+
+/// Simulates `messages` on the chain LCD and derives a gas limit and fee from
+/// the gas actually used.
+pub fn estimate(
+    chain: &Chain,
+    messages: &[Message],
+    signers: &[String],
+    gas_adjustment: f64,
+) -> Result<GasEstimate, anyhow::Error> {
+    let gas_used = simulate(chain, messages, signers)?;
+    let gas_limit = adjust_gas(gas_used, gas_adjustment)?;
+
+    Ok(GasEstimate {
+        gas_used,
+        gas_limit,
+        fee: fee_for_gas(chain, gas_limit)?,
+    })
+}
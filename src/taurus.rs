@@ -1,11 +1,20 @@
-use crate::config::Wallet;
-use anyhow::bail;
+use crate::address::{is_evm, same_address};
+use crate::amount::Amount;
+use crate::auth::{ApiKeyAuth, Auth, BearerAuth, NoAuth, SignedRequest};
+use crate::coin::Coin;
+use crate::config::{AuthMode, Chain, Wallet};
+use crate::currency::Currencies;
+use crate::date::Timestamp;
+use crate::notify::{Event, Notifier, Notifiers};
+use anyhow::{anyhow, bail};
 use reqwest::blocking::Client;
+use reqwest::{Method, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::*;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq)]
 pub struct NodeInfo {
     pub name: String,
     pub version: String,
@@ -14,15 +23,146 @@ pub struct NodeInfo {
     pub commit: String,
 }
 
-#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+/// Oldest Taurus API version this client is tested against.
+pub const MIN_API_VERSION: [u64; 2] = [3, 0];
+
+/// Numeric components of a version such as `"v0.47.5"`, up to the first
+/// non-numeric one.
+pub fn parse_version(version: &str) -> Vec<u64> {
+    version
+        .trim()
+        .trim_start_matches('v')
+        .split(['.', '-', '+'])
+        .map_while(|v| v.parse().ok())
+        .collect()
+}
+
+/// Whether `version` is `MIN_API_VERSION` or newer, missing components
+/// counting as zero so that `"3"` is `3.0`.
+pub fn supported_version(version: &str) -> bool {
+    let mut version = parse_version(version);
+    if version.len() < MIN_API_VERSION.len() {
+        version.resize(MIN_API_VERSION.len(), 0);
+    }
+
+    version.as_slice() >= MIN_API_VERSION.as_slice()
+}
+
+/// Outcome of `Taurus::health_check`, for readiness probes.
+#[derive(Serialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct HealthReport {
+    pub reachable: bool,
+    pub authenticated: bool,
+    pub version_supported: bool,
+    pub node: Option<NodeInfo>,
+    pub latency_ms: u64,
+    pub errors: Vec<String>,
+}
+
+impl HealthReport {
+    pub fn is_healthy(&self) -> bool {
+        self.reachable && self.authenticated && self.version_supported
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ScoreProvider {
+    Chainalysis,
+    Elliptic,
+    Scorechain,
+    Coinfirm,
+    Other(String),
+}
+
+impl std::fmt::Display for ScoreProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScoreProvider::Chainalysis => write!(f, "chainalysis"),
+            ScoreProvider::Elliptic => write!(f, "elliptic"),
+            ScoreProvider::Scorechain => write!(f, "scorechain"),
+            ScoreProvider::Coinfirm => write!(f, "coinfirm"),
+            ScoreProvider::Other(provider) => write!(f, "{}", provider),
+        }
+    }
+}
+
+impl std::str::FromStr for ScoreProvider {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "chainalysis" => ScoreProvider::Chainalysis,
+            "elliptic" => ScoreProvider::Elliptic,
+            "scorechain" => ScoreProvider::Scorechain,
+            "coinfirm" => ScoreProvider::Coinfirm,
+            _ => ScoreProvider::Other(s.to_string()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ScoreProvider {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let provider = String::deserialize(deserializer)?;
+        provider.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// providers not returning a numeric score (e.g. a rating) leave it unset
+fn deserialize_score<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<f64>, D::Error> {
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .filter(|s: &f64| s.is_finite()))
+}
+
+/// Risk score of an address given by a compliance provider.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Score {
-    id: String,
-    provider: String,
+    pub id: String,
+    pub provider: ScoreProvider,
     #[serde(rename(deserialize = "type"))]
-    score_type: String,
-    score: String,
-    update_date: String,
+    pub score_type: String,
+    #[serde(default, deserialize_with = "deserialize_score")]
+    pub score: Option<f64>,
+    #[serde(default, deserialize_with = "crate::date::deserialize_option")]
+    pub update_date: Option<Timestamp>,
+}
+
+impl Score {
+    /// Whether the score is above `max_score`, a missing or non-numeric
+    /// score, e.g. a `"HIGH"` rating, counting as above.
+    pub fn exceeds(&self, max_score: f64) -> bool {
+        self.score.is_none_or(|score| score > max_score)
+    }
+}
+
+/// Refuses `address` when one of its scores is above `max_score` or cannot be
+/// compared to it.
+pub fn check_scores(address: &str, scores: &[Score], max_score: f64) -> Result<(), anyhow::Error> {
+    if let Some(score) = scores.iter().find(|s| s.exceeds(max_score)) {
+        match score.score {
+            Some(value) => bail!(
+                "address {} has a {} risk score of {} above {}",
+                address,
+                score.provider,
+                value,
+                max_score
+            ),
+            None => bail!(
+                "address {} has a {} risk score that cannot be compared to {}",
+                address,
+                score.provider,
+                max_score
+            ),
+        }
+    }
+
+    Ok(())
 }
 
 #[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -41,6 +181,45 @@ pub struct CurrencyInfo {
     currency_type: String,
 }
 
+impl CurrencyInfo {
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn symbol(&self) -> &str {
+        self.symbol.as_str()
+    }
+
+    pub fn blockchain(&self) -> &str {
+        self.blockchain.as_str()
+    }
+
+    pub fn contract_address(&self) -> Option<&str> {
+        self.contract_address.as_deref()
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn decimals(&self) -> Result<u32, anyhow::Error> {
+        Ok(self.decimals.parse()?)
+    }
+
+    pub fn amount(&self, value: u128) -> Result<Amount, anyhow::Error> {
+        Ok(Amount::new(value, self.decimals()?, self.symbol()))
+    }
+
+    pub fn parse_amount(&self, value: &str) -> Result<Amount, anyhow::Error> {
+        Amount::from_decimal_str(value, self.decimals()?, self.symbol())
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CurrenciesResponse {
+    pub result: Vec<CurrencyInfo>,
+}
+
 #[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WalletInfo {
@@ -52,24 +231,107 @@ pub struct WalletInfo {
     pub container: Option<String>,
     pub account_path: String,
     pub is_omnibus: Option<bool>,
-    pub creation_date: String,
-    pub update_date: String,
+    #[serde(deserialize_with = "crate::date::deserialize_option")]
+    pub creation_date: Option<Timestamp>,
+    #[serde(deserialize_with = "crate::date::deserialize_option")]
+    pub update_date: Option<Timestamp>,
     pub blockchain: String,
     pub currency_info: CurrencyInfo,
+    #[serde(default)]
+    pub attributes: Vec<Attributes>,
 }
 
-#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+impl WalletInfo {
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        find_attribute(&self.attributes, key)
+    }
+
+    pub fn total_confirmed(&self) -> Result<Amount, anyhow::Error> {
+        self.balance.total_confirmed_in(&self.currency_info)
+    }
+
+    pub fn available_confirmed(&self) -> Result<Amount, anyhow::Error> {
+        self.balance.available_confirmed_in(&self.currency_info)
+    }
+
+    pub fn reserved_confirmed(&self) -> Result<Amount, anyhow::Error> {
+        self.balance.reserved_confirmed_in(&self.currency_info)
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Attributes {
-    key: String,
-    value: String,
-    id: String,
-    content_type: String,
-    owner: String,
-    #[serde(rename(deserialize = "type"))]
-    attribute_type: String,
-    subtype: String,
-    isfile: bool,
+    pub id: String,
+    pub key: String,
+    pub value: String,
+    #[serde(default)]
+    pub content_type: String,
+    #[serde(default)]
+    pub owner: String,
+    #[serde(rename = "type", default)]
+    pub attribute_type: String,
+    #[serde(default)]
+    pub subtype: String,
+    #[serde(default)]
+    pub isfile: bool,
+}
+
+impl Attributes {
+    pub fn matches(&self, key: &str, value: &str) -> bool {
+        self.key == key && self.value == value
+    }
+}
+
+fn find_attribute<'a>(attributes: &'a [Attributes], key: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|a| a.key == key)
+        .map(|a| a.value.as_str())
+}
+
+/// An attribute to attach to a wallet or an address, e.g. our customer id.
+#[derive(Serialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeParams {
+    pub key: String,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub attribute_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isfile: Option<bool>,
+}
+
+impl AttributeParams {
+    pub fn new(key: &str, value: &str) -> Self {
+        AttributeParams {
+            key: key.to_string(),
+            value: value.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.key.trim().is_empty() {
+            bail!("attribute key is empty");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+struct CreateAttributesParams {
+    attributes: Vec<AttributeParams>,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AttributesResponse {
+    pub result: Vec<Attributes>,
 }
 
 #[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -89,7 +351,39 @@ pub struct Balance {
     pub reserved_unconfirmed: u128,
 }
 
-#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+impl Balance {
+    pub fn total_confirmed_in(&self, currency: &CurrencyInfo) -> Result<Amount, anyhow::Error> {
+        currency.amount(self.total_confirmed)
+    }
+
+    pub fn total_unconfirmed_in(&self, currency: &CurrencyInfo) -> Result<Amount, anyhow::Error> {
+        currency.amount(self.total_unconfirmed)
+    }
+
+    pub fn available_confirmed_in(&self, currency: &CurrencyInfo) -> Result<Amount, anyhow::Error> {
+        currency.amount(self.available_confirmed)
+    }
+
+    pub fn available_unconfirmed_in(
+        &self,
+        currency: &CurrencyInfo,
+    ) -> Result<Amount, anyhow::Error> {
+        currency.amount(self.available_unconfirmed)
+    }
+
+    pub fn reserved_confirmed_in(&self, currency: &CurrencyInfo) -> Result<Amount, anyhow::Error> {
+        currency.amount(self.reserved_confirmed)
+    }
+
+    pub fn reserved_unconfirmed_in(
+        &self,
+        currency: &CurrencyInfo,
+    ) -> Result<Amount, anyhow::Error> {
+        currency.amount(self.reserved_unconfirmed)
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Addresses {
     pub id: String,
@@ -98,6 +392,20 @@ pub struct Addresses {
     pub address: String,
     pub label: String,
     pub signature: String,
+    #[serde(default)]
+    pub attributes: Vec<Attributes>,
+    #[serde(default)]
+    pub scores: Vec<Score>,
+}
+
+impl Addresses {
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        find_attribute(&self.attributes, key)
+    }
+
+    pub fn check_risk(&self, max_score: f64) -> Result<(), anyhow::Error> {
+        check_scores(self.address.as_str(), &self.scores, max_score)
+    }
 }
 
 #[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -106,7 +414,7 @@ pub struct WalletResponse {
     pub total_items: Option<String>,
 }
 
-#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Clone, Debug, PartialEq)]
 pub struct AddressesResponse {
     pub result: Option<Vec<Addresses>>,
     pub total_items: Option<String>,
@@ -132,12 +440,70 @@ pub struct AccountInfo {
     pub account_number: String,
 }
 
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BroadcastKind {
+    #[default]
+    Sync,
+    Async,
+    /// Removed from Cosmos-SDK 0.47, see `BroadcastKind::for_sdk_version`.
+    Block,
+}
+
+impl BroadcastKind {
+    /// Falls back to `Sync` when `Block` is requested on a chain running
+    /// Cosmos-SDK 0.47 or newer, which dropped block mode.
+    pub fn for_sdk_version(self, sdk_version: Option<&str>) -> Self {
+        if self != BroadcastKind::Block {
+            return self;
+        }
+
+        let version = parse_version(sdk_version.unwrap_or_default());
+
+        let unsupported = match version.as_slice() {
+            [0, minor, ..] => *minor >= 47,
+            [major, ..] => *major >= 1,
+            _ => false,
+        };
+
+        if unsupported {
+            log::warn!("block broadcast mode is not supported by this chain, using sync");
+            BroadcastKind::Sync
+        } else {
+            self
+        }
+    }
+}
+
+impl std::fmt::Display for BroadcastKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BroadcastKind::Sync => write!(f, "sync"),
+            BroadcastKind::Async => write!(f, "async"),
+            BroadcastKind::Block => write!(f, "block"),
+        }
+    }
+}
+
+impl std::str::FromStr for BroadcastKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sync" => Ok(BroadcastKind::Sync),
+            "async" => Ok(BroadcastKind::Async),
+            "block" => Ok(BroadcastKind::Block),
+            _ => bail!("unknown broadcast kind: {}", s),
+        }
+    }
+}
+
 #[derive(Serialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct RequestParams {
     pub chain_id: String,
     pub signers: Vec<u16>,
-    pub broadcast_kind: String,
+    pub broadcast_kind: BroadcastKind,
     pub fee_denom: String,
     pub gas_limit: String,
     pub fee: String,
@@ -183,14 +549,108 @@ pub struct ApproveParams {
     pub call: CallParams,
 }
 
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressType {
+    #[default]
+    Individual,
+    Exchange,
+    Contract,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ContractType {
+    #[serde(rename = "ERC20")]
+    Erc20,
+    #[serde(rename = "ERC721")]
+    Erc721,
+    #[serde(rename = "ERC1155")]
+    Erc1155,
+    #[serde(rename = "CW20")]
+    Cw20,
+}
+
+impl std::fmt::Display for ContractType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContractType::Erc20 => write!(f, "ERC20"),
+            ContractType::Erc721 => write!(f, "ERC721"),
+            ContractType::Erc1155 => write!(f, "ERC1155"),
+            ContractType::Cw20 => write!(f, "CW20"),
+        }
+    }
+}
+
 #[derive(Serialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct WhitelistParams {
     pub blockchain: Option<String>,
     pub label: String,
     pub address: String,
-    pub address_type: String,
-    pub contract_type: Option<String>,
+    pub address_type: AddressType,
+    pub contract_type: Option<ContractType>,
+}
+
+impl WhitelistParams {
+    pub fn address(
+        blockchain: &str,
+        label: &str,
+        address: &str,
+        address_type: AddressType,
+    ) -> Result<Self, anyhow::Error> {
+        if address_type == AddressType::Contract {
+            bail!("use WhitelistParams::contract to whitelist a contract");
+        }
+
+        let params = WhitelistParams {
+            blockchain: Some(blockchain.to_string()),
+            label: label.to_string(),
+            address: address.to_string(),
+            address_type,
+            contract_type: None,
+        };
+        params.validate()?;
+
+        Ok(params)
+    }
+
+    pub fn contract(
+        blockchain: &str,
+        label: &str,
+        address: &str,
+        contract_type: ContractType,
+    ) -> Result<Self, anyhow::Error> {
+        let params = WhitelistParams {
+            blockchain: Some(blockchain.to_string()),
+            label: label.to_string(),
+            address: address.to_string(),
+            address_type: AddressType::Contract,
+            contract_type: Some(contract_type),
+        };
+        params.validate()?;
+
+        Ok(params)
+    }
+
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.address.is_empty() {
+            bail!("whitelist address is empty");
+        }
+
+        match (self.address_type, self.contract_type) {
+            (AddressType::Contract, None) => bail!("contract whitelist requires a contract type"),
+            (AddressType::Individual | AddressType::Exchange, Some(_)) => {
+                bail!("contract type is only allowed for contract addresses")
+            }
+            _ => {}
+        }
+
+        if let Some(blockchain) = &self.blockchain {
+            crate::address::validate_for_blockchain(self.address.as_str(), blockchain)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq, Default)]
@@ -199,8 +659,12 @@ pub struct SignedRequests {
     pub id: String,
     pub signed_request: String,
     pub status: String,
-    pub creation_date: String,
-    pub update_date: String,
+    #[serde(deserialize_with = "crate::date::deserialize_option")]
+    pub creation_date: Option<Timestamp>,
+    #[serde(deserialize_with = "crate::date::deserialize_option")]
+    pub update_date: Option<Timestamp>,
+    pub hash: Option<String>,
+    pub block: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq, Default)]
@@ -209,7 +673,8 @@ pub struct Trails {
     pub user_id: String,
     pub external_user_id: String,
     pub action: String,
-    pub date: Option<String>,
+    #[serde(default, deserialize_with = "crate::date::deserialize_option")]
+    pub date: Option<Timestamp>,
     pub request_status: String,
 }
 
@@ -245,12 +710,122 @@ pub struct RequestInfos {
     pub metadata: Option<Metadata>,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RequestStatus {
+    Created,
+    Approving,
+    Approved,
+    Signed,
+    Broadcasting,
+    Broadcasted,
+    Confirmed,
+    Rejected,
+    Canceled,
+    Expired,
+    PermanentFailure,
+    Unknown,
+}
+
+impl RequestStatus {
+    /// Whether Taurus will not move the request to another status anymore.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            RequestStatus::Confirmed
+                | RequestStatus::Rejected
+                | RequestStatus::Canceled
+                | RequestStatus::Expired
+                | RequestStatus::PermanentFailure
+        )
+    }
+
+    pub fn is_success(&self) -> bool {
+        *self == RequestStatus::Confirmed
+    }
+}
+
+impl std::str::FromStr for RequestStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_uppercase().replace([' ', '-'], "_").as_str() {
+            "CREATED" | "PENDING" => RequestStatus::Created,
+            "APPROVING" | "PARTIALLY_APPROVED" => RequestStatus::Approving,
+            "APPROVED" | "HSM_READY" => RequestStatus::Approved,
+            "HSM_SIGNED" | "SIGNED" | "READY_TO_BROADCAST" => RequestStatus::Signed,
+            "BROADCASTING" => RequestStatus::Broadcasting,
+            "BROADCASTED" | "MINED" => RequestStatus::Broadcasted,
+            "CONFIRMED" => RequestStatus::Confirmed,
+            "REJECTED" => RequestStatus::Rejected,
+            "CANCELED" | "CANCELLED" => RequestStatus::Canceled,
+            "EXPIRED" => RequestStatus::Expired,
+            "PERMANENT_FAILURE" | "FAILED" => RequestStatus::PermanentFailure,
+            _ => RequestStatus::Unknown,
+        })
+    }
+}
+
+impl RequestInfos {
+    pub fn request_status(&self) -> RequestStatus {
+        self.status.parse().unwrap_or(RequestStatus::Unknown)
+    }
+
+    /// Hashes of the transactions broadcast for this request.
+    pub fn tx_hashes(&self) -> Vec<String> {
+        self.signed_requests
+            .iter()
+            .flatten()
+            .filter_map(|s| s.hash.clone())
+            .filter(|h| !h.is_empty())
+            .collect()
+    }
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct RequestResponse {
     pub result: RequestInfos,
 }
 
+#[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestsResponse {
+    pub result: Option<Vec<RequestInfos>>,
+    pub total_items: Option<String>,
+}
+
+impl Page for RequestsResponse {
+    type Item = RequestInfos;
+
+    fn into_parts(self) -> (Vec<RequestInfos>, Option<String>) {
+        (self.result.unwrap_or_default(), self.total_items)
+    }
+}
+
+/// Filters for `Taurus::requests`, dates being RFC 3339 strings.
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct RequestFilter {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub currency: Option<String>,
+    pub status: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+impl RequestFilter {
+    pub fn to_query(&self) -> String {
+        to_query(&[
+            ("from", self.from.clone()),
+            ("to", self.to.clone()),
+            ("currency", self.currency.clone()),
+            ("status", self.status.clone()),
+            ("limit", self.limit.map(|l| l.to_string())),
+            ("offset", self.offset.map(|o| o.to_string())),
+        ])
+    }
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct WhitelistInfos {
@@ -263,10 +838,333 @@ pub struct WhitelistResponse {
     pub result: WhitelistInfos,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum WhitelistStatus {
+    Created,
+    Pending,
+    PartiallyApproved,
+    Approved,
+    Rejected,
+    Deleted,
+    Unknown,
+}
+
+impl std::str::FromStr for WhitelistStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().replace(['_', ' '], "").as_str() {
+            "created" | "new" => WhitelistStatus::Created,
+            "pending" | "pendingapproval" => WhitelistStatus::Pending,
+            "partiallyapproved" | "partiallysigned" => WhitelistStatus::PartiallyApproved,
+            "approved" | "signed" => WhitelistStatus::Approved,
+            "rejected" => WhitelistStatus::Rejected,
+            "deleted" => WhitelistStatus::Deleted,
+            _ => WhitelistStatus::Unknown,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for WhitelistStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+        status.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WhitelistedAddress {
+    pub id: String,
+    pub blockchain: Option<String>,
+    pub network: Option<String>,
+    pub status: WhitelistStatus,
+    pub address: Option<String>,
+    pub label: Option<String>,
+    pub address_type: Option<String>,
+    pub contract_type: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    #[serde(default)]
+    pub scores: Vec<Score>,
+}
+
+impl WhitelistedAddress {
+    pub fn is_approved(&self) -> bool {
+        self.status == WhitelistStatus::Approved
+    }
+
+    pub fn check_risk(&self, max_score: f64) -> Result<(), anyhow::Error> {
+        check_scores(
+            self.address().unwrap_or_default().as_str(),
+            &self.scores,
+            max_score,
+        )
+    }
+
+    fn metadata_field(&self, key: &str) -> Option<String> {
+        self.metadata
+            .as_ref()?
+            .get("payload")?
+            .get(key)?
+            .as_str()
+            .map(|v| v.to_string())
+    }
+
+    /// Whitelisted address, read from the signed metadata when not returned
+    /// at the top level.
+    pub fn address(&self) -> Option<String> {
+        self.address
+            .clone()
+            .or_else(|| self.metadata_field("address"))
+    }
+
+    pub fn blockchain(&self) -> Option<String> {
+        self.blockchain
+            .clone()
+            .or_else(|| self.metadata_field("blockchain"))
+    }
+
+    pub fn contract_type(&self) -> Option<String> {
+        self.contract_type
+            .clone()
+            .or_else(|| self.metadata_field("contractType"))
+    }
+
+    /// Whether this entry already covers `params`; rejected and deleted
+    /// entries never do.
+    pub fn matches_params(&self, params: &WhitelistParams) -> bool {
+        if matches!(
+            self.status,
+            WhitelistStatus::Rejected | WhitelistStatus::Deleted
+        ) {
+            return false;
+        }
+
+        let same_blockchain = match &params.blockchain {
+            Some(blockchain) => self
+                .blockchain()
+                .is_some_and(|b| b.eq_ignore_ascii_case(blockchain)),
+            None => true,
+        };
+        let same_contract_type = match &params.contract_type {
+            Some(contract_type) => self
+                .contract_type()
+                .is_some_and(|c| c.eq_ignore_ascii_case(&contract_type.to_string())),
+            None => true,
+        };
+
+        self.has_address(params.address.as_str()) && same_blockchain && same_contract_type
+    }
+
+    pub fn matches(&self, address: &str, blockchain: &str) -> bool {
+        self.has_address(address)
+            && self
+                .blockchain()
+                .is_some_and(|b| b.eq_ignore_ascii_case(blockchain))
+    }
+
+    fn has_address(&self, address: &str) -> bool {
+        let blockchain = self.blockchain().unwrap_or_default();
+
+        self.address()
+            .is_some_and(|a| same_address(a.as_str(), address, blockchain.as_str()))
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WhitelistedAddressesResponse {
+    pub result: Option<Vec<WhitelistedAddress>>,
+    pub total_items: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WhitelistedAddressResponse {
+    pub result: WhitelistedAddress,
+}
+
+impl Page for WhitelistedAddressesResponse {
+    type Item = WhitelistedAddress;
+
+    fn into_parts(self) -> (Vec<WhitelistedAddress>, Option<String>) {
+        (self.result.unwrap_or_default(), self.total_items)
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OutgoingParams {
+    pub from_address_id: String,
+    pub to_whitelisted_address_id: String,
+    pub amount: String,
+    pub currency: Option<String>,
+    pub comment: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionDirection {
+    Incoming,
+    Outgoing,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionParty {
+    pub address: String,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub amount: Option<u128>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub id: String,
+    pub direction: TransactionDirection,
+    pub currency: String,
+    pub blockchain: Option<String>,
+    pub hash: String,
+    pub block: Option<String>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub amount: u128,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub fee: Option<u128>,
+    #[serde(default)]
+    pub sources: Vec<TransactionParty>,
+    #[serde(default)]
+    pub destinations: Vec<TransactionParty>,
+    pub is_confirmed: Option<bool>,
+    #[serde(default, deserialize_with = "crate::date::deserialize_option")]
+    pub reception_date: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::date::deserialize_option")]
+    pub confirmation_date: Option<Timestamp>,
+    #[serde(default)]
+    pub scores: Vec<Score>,
+}
+
+impl Transaction {
+    pub fn is_confirmed(&self) -> bool {
+        self.is_confirmed.unwrap_or(false)
+    }
+
+    pub fn is_incoming(&self) -> bool {
+        self.direction == TransactionDirection::Incoming
+    }
+
+    /// Whether `address` is one of the receiving addresses.
+    pub fn is_to(&self, address: &str) -> bool {
+        self.destinations.iter().any(|d| d.address == address)
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionsResponse {
+    pub result: Option<Vec<Transaction>>,
+    pub total_items: Option<String>,
+}
+
+impl Page for TransactionsResponse {
+    type Item = Transaction;
+
+    fn into_parts(self) -> (Vec<Transaction>, Option<String>) {
+        (self.result.unwrap_or_default(), self.total_items)
+    }
+}
+
+/// Filters for `Taurus::transactions`, dates being RFC 3339 strings.
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct TransactionFilter {
+    pub address: Option<String>,
+    pub wallet_id: Option<String>,
+    pub currency: Option<String>,
+    pub direction: Option<TransactionDirection>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+impl TransactionFilter {
+    pub fn to_query(&self) -> String {
+        to_query(&[
+            ("address", self.address.clone()),
+            ("walletId", self.wallet_id.clone()),
+            ("currency", self.currency.clone()),
+            (
+                "direction",
+                self.direction.and_then(|d| match d {
+                    TransactionDirection::Incoming => Some("incoming".to_string()),
+                    TransactionDirection::Outgoing => Some("outgoing".to_string()),
+                    TransactionDirection::Unknown => None,
+                }),
+            ),
+            ("from", self.from.clone()),
+            ("to", self.to.clone()),
+            ("limit", self.limit.map(|l| l.to_string())),
+            ("offset", self.offset.map(|o| o.to_string())),
+        ])
+    }
+}
+
+/// Url encodes the `params` that are set, in order.
+fn to_query(params: &[(&str, Option<String>)]) -> String {
+    let mut query = reqwest::Url::parse("http://localhost/").expect("static url");
+    {
+        let mut pairs = query.query_pairs_mut();
+        for (key, value) in params {
+            if let Some(value) = value {
+                pairs.append_pair(key, value);
+            }
+        }
+    }
+
+    query.query().unwrap_or_default().to_string()
+}
+
+/// One page of a Taurus listing: its items and the total count, if given.
+pub(crate) trait Page {
+    type Item;
+
+    fn into_parts(self) -> (Vec<Self::Item>, Option<String>);
+}
+
+/// Fetches every page with `fetch(limit, offset)` until the total given by
+/// Taurus is reached, or without a total until an empty page: Taurus may
+/// return fewer items than `page_size`.
+pub(crate) fn all_pages<P: Page>(
+    page_size: usize,
+    mut fetch: impl FnMut(usize, usize) -> Result<P, anyhow::Error>,
+) -> Result<Vec<P::Item>, anyhow::Error> {
+    let mut items = Vec::new();
+
+    loop {
+        let (page, total) = fetch(page_size, items.len())?.into_parts();
+        let count = page.len();
+
+        items.extend(page);
+
+        let total = total.and_then(|t| t.parse::<usize>().ok());
+        if count == 0 || total.is_some_and(|total| items.len() >= total) {
+            return Ok(items);
+        }
+    }
+}
+
+const WHITELIST_PAGE_SIZE: usize = 100;
+const TRANSACTION_PAGE_SIZE: usize = 100;
+const REQUEST_PAGE_SIZE: usize = 100;
+const REQUEST_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
 pub struct Taurus {
     address: String,
     client: Client,
-    token: Option<String>,
+    auth: Box<dyn Auth>,
+    notifiers: Notifiers,
+    currencies: Mutex<Option<Arc<Currencies>>>,
 }
 
 impl Taurus {
@@ -278,14 +1176,34 @@ impl Taurus {
         let mut taurus = Taurus {
             address: cfg.api_url.clone(),
             client,
-            token: None,
+            auth: Box::new(NoAuth),
+            notifiers: Notifiers::new(),
+            currencies: Mutex::new(None),
         };
 
-        taurus.login(cfg.mail.as_str(), cfg.passwd.as_str())?;
+        match cfg.auth {
+            AuthMode::Password => taurus.login(cfg.mail.as_str(), cfg.passwd.as_str())?,
+            AuthMode::ApiKey => {
+                let key = cfg
+                    .api_key
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("api_key auth without api_key"))?;
+                let secret = cfg
+                    .api_secret
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("api_key auth without api_secret"))?;
+
+                taurus.set_auth(ApiKeyAuth::new(key, secret)?);
+            }
+        }
 
         Ok(taurus)
     }
 
+    pub fn set_auth<A: Auth + 'static>(&mut self, auth: A) {
+        self.auth = Box::new(auth);
+    }
+
     pub fn login(&mut self, email: &str, password: &str) -> Result<(), anyhow::Error> {
         let token = self.token(TokenParams {
             email: email.to_string(),
@@ -295,29 +1213,94 @@ impl Taurus {
 
         log::info!("Token generated");
 
-        self.token = Some(format!("Bearer {}", token.result));
+        self.set_auth(BearerAuth::new(token.result.as_str()));
 
         Ok(())
     }
 
-    fn get<T: serde::de::DeserializeOwned + Clone>(
+    /// Sends request and whitelist lifecycle events to `notifiers`, see
+    /// `Notifiers::from_config`.
+    pub fn set_notifiers(&mut self, notifiers: Notifiers) {
+        self.notifiers = notifiers;
+    }
+
+    pub fn add_notifier<N: Notifier + Send + Sync + 'static>(&mut self, notifier: N) {
+        self.notifiers.add(notifier);
+    }
+
+    fn notify(&self, event: &Event) {
+        self.notifiers.notify(event);
+    }
+
+    fn notify_new_request(
+        &self,
+        response: RequestResponse,
+    ) -> Result<RequestResponse, anyhow::Error> {
+        for event in Event::for_new_request(&response.result) {
+            self.notify(&event);
+        }
+
+        Ok(response)
+    }
+
+    /// Sends a request authenticated by the current `Auth`, returning the
+    /// status and the payload.
+    fn send(
         &self,
+        method: Method,
         endpoint: &str,
-    ) -> Result<T, anyhow::Error> {
-        log::debug!("GET {}", endpoint);
-        let mut request_builder = self.client.get(format!("{}{}", self.address, endpoint));
+        body: Option<String>,
+    ) -> Result<(StatusCode, String), anyhow::Error> {
+        log::debug!("{} {}", method, endpoint);
+        let url = reqwest::Url::parse(format!("{}{}", self.address, endpoint).as_str())?;
+        let content_type = if body.is_some() {
+            "application/json"
+        } else {
+            ""
+        };
+        let body = body.unwrap_or_default();
+        if !body.is_empty() {
+            log::debug!("\t Body {}", body);
+        }
+
+        let authorization = self.auth.authorization(&SignedRequest {
+            method: method.as_str(),
+            host: url.host_str().unwrap_or_default(),
+            path: url.path(),
+            query: url.query().unwrap_or_default(),
+            content_type,
+            body: body.as_str(),
+        })?;
 
-        if let Some(bearer) = self.token.clone() {
-            request_builder = request_builder.header("Authorization", bearer);
+        let mut request_builder = self.client.request(method, url);
+        if !content_type.is_empty() {
+            request_builder = request_builder
+                .header("Content-Type", content_type)
+                .body(body);
+        }
+        if let Some(authorization) = authorization {
+            request_builder = request_builder.header("Authorization", authorization);
         }
         let request = request_builder.send()?;
 
-        let data = &request.text()?;
+        let status = request.status();
+        let data = request.text()?;
         log::trace!("-> payload\n{}", data);
 
-        let output = serde_json::from_str::<T>(data);
+        Ok((status, data))
+    }
+
+    fn get<T: serde::de::DeserializeOwned + Clone>(
+        &self,
+        endpoint: &str,
+    ) -> Result<T, anyhow::Error> {
+        let (status, data) = self.send(Method::GET, endpoint, None)?;
 
-        Ok(output.unwrap())
+        if !status.is_success() {
+            bail!("GET {} failed with {}: {}", endpoint, status, data);
+        }
+
+        Ok(serde_json::from_str::<T>(&data)?)
     }
 
     fn post<
@@ -328,35 +1311,234 @@ impl Taurus {
         endpoint: &str,
         data: &U,
     ) -> Result<T, anyhow::Error> {
-        log::debug!("POST {}", endpoint);
         let body = serde_json::to_string(data)?;
-        log::debug!("\t Body {}", body);
-        let mut request_builder = self
-            .client
-            .post(format!("{}{}", self.address, endpoint))
-            .body(body)
-            .header("Content-Type", "application/json");
-        if let Some(bearer) = self.token.clone() {
-            request_builder = request_builder.header("Authorization", bearer);
+        let (status, data) = self.send(Method::POST, endpoint, Some(body))?;
+
+        if !status.is_success() {
+            bail!("POST {} failed with {}: {}", endpoint, status, data);
         }
 
-        let request = request_builder.send()?;
-        let data = &request.text()?;
-        log::trace!("-> payload\n{}", data);
+        Ok(serde_json::from_str::<T>(&data)?)
+    }
 
-        let output = serde_json::from_str::<T>(data);
+    fn delete(&self, endpoint: &str) -> Result<(), anyhow::Error> {
+        let (status, data) = self.send(Method::DELETE, endpoint, None)?;
+
+        if !status.is_success() {
+            bail!("DELETE {} failed with {}: {}", endpoint, status, data);
+        }
 
-        Ok(output.unwrap())
+        Ok(())
     }
 
     fn token(&self, params: TokenParams) -> Result<Token, anyhow::Error> {
         self.post("/api/rest/v1/authentication/token", &params)
     }
 
+    pub fn node_info(&self) -> Result<NodeInfo, anyhow::Error> {
+        self.get("/api/rest/v1/info")
+    }
+
+    /// Checks that Taurus is reachable, that our credentials are accepted and
+    /// that it runs a supported API version. Never fails: problems are listed
+    /// in the report.
+    pub fn health_check(&self) -> HealthReport {
+        let start = std::time::Instant::now();
+        let mut report = HealthReport::default();
+
+        match self.node_info() {
+            Ok(node) => {
+                report.reachable = true;
+                report.version_supported = supported_version(node.version.as_str());
+                if !report.version_supported {
+                    report.errors.push(format!(
+                        "unsupported taurus version {}, expected {}.{} or newer",
+                        node.version, MIN_API_VERSION[0], MIN_API_VERSION[1]
+                    ));
+                }
+                report.node = Some(node);
+            }
+            Err(e) => report.errors.push(format!("node info: {}", e)),
+        }
+        report.latency_ms = start.elapsed().as_millis() as u64;
+
+        if self.auth.is_anonymous() {
+            report.errors.push("not logged in".to_string());
+        } else {
+            match self.get::<serde_json::Value>("/api/rest/v1/wallets?limit=1") {
+                Ok(_) => {
+                    report.authenticated = true;
+                    report.reachable = true;
+                }
+                Err(e) => report.errors.push(format!("authentication: {}", e)),
+            }
+        }
+
+        report
+    }
+
     pub fn addresses(&self) -> Result<AddressesResponse, anyhow::Error> {
         self.get("/api/rest/v1/addresses")
     }
 
+    pub fn wallets(&self) -> Result<WalletResponse, anyhow::Error> {
+        self.get("/api/rest/v1/wallets")
+    }
+
+    pub fn wallet_attributes(&self, wallet_id: &str) -> Result<Vec<Attributes>, anyhow::Error> {
+        let response: AttributesResponse =
+            self.get(format!("/api/rest/v1/wallets/{}/attributes", wallet_id).as_str())?;
+
+        Ok(response.result)
+    }
+
+    pub fn add_wallet_attributes(
+        &self,
+        wallet_id: &str,
+        attributes: Vec<AttributeParams>,
+    ) -> Result<Vec<Attributes>, anyhow::Error> {
+        self.add_attributes(
+            format!("/api/rest/v1/wallets/{}/attributes", wallet_id).as_str(),
+            attributes,
+        )
+    }
+
+    pub fn delete_wallet_attribute(
+        &self,
+        wallet_id: &str,
+        attribute_id: &str,
+    ) -> Result<(), anyhow::Error> {
+        self.delete(
+            format!(
+                "/api/rest/v1/wallets/{}/attributes/{}",
+                wallet_id, attribute_id
+            )
+            .as_str(),
+        )
+    }
+
+    pub fn address_attributes(&self, address_id: &str) -> Result<Vec<Attributes>, anyhow::Error> {
+        let response: AttributesResponse =
+            self.get(format!("/api/rest/v1/addresses/{}/attributes", address_id).as_str())?;
+
+        Ok(response.result)
+    }
+
+    pub fn add_address_attributes(
+        &self,
+        address_id: &str,
+        attributes: Vec<AttributeParams>,
+    ) -> Result<Vec<Attributes>, anyhow::Error> {
+        self.add_attributes(
+            format!("/api/rest/v1/addresses/{}/attributes", address_id).as_str(),
+            attributes,
+        )
+    }
+
+    pub fn delete_address_attribute(
+        &self,
+        address_id: &str,
+        attribute_id: &str,
+    ) -> Result<(), anyhow::Error> {
+        self.delete(
+            format!(
+                "/api/rest/v1/addresses/{}/attributes/{}",
+                address_id, attribute_id
+            )
+            .as_str(),
+        )
+    }
+
+    fn add_attributes(
+        &self,
+        endpoint: &str,
+        attributes: Vec<AttributeParams>,
+    ) -> Result<Vec<Attributes>, anyhow::Error> {
+        if attributes.is_empty() {
+            bail!("no attribute to add");
+        }
+        for attribute in &attributes {
+            attribute.validate()?;
+        }
+
+        let response: AttributesResponse =
+            self.post(endpoint, &CreateAttributesParams { attributes })?;
+
+        Ok(response.result)
+    }
+
+    /// Wallets holding the attribute `key` set to `value`.
+    pub fn wallets_with_attribute(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<Vec<WalletInfo>, anyhow::Error> {
+        Ok(self
+            .wallets()?
+            .result
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|w| w.attributes.iter().any(|a| a.matches(key, value)))
+            .collect())
+    }
+
+    /// Addresses holding the attribute `key` set to `value`.
+    pub fn addresses_with_attribute(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<Vec<Addresses>, anyhow::Error> {
+        Ok(self
+            .addresses()?
+            .result
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|a| a.attributes.iter().any(|a| a.matches(key, value)))
+            .collect())
+    }
+
+    /// Supported currencies, fetched once and then served from memory.
+    pub fn currencies(&self) -> Result<Arc<Currencies>, anyhow::Error> {
+        let mut cache = self
+            .currencies
+            .lock()
+            .map_err(|_| anyhow!("currency cache poisoned"))?;
+
+        if let Some(currencies) = cache.as_ref() {
+            return Ok(currencies.clone());
+        }
+
+        let response: CurrenciesResponse = self.get("/api/rest/v1/currencies")?;
+        let currencies = Arc::new(Currencies::new(response.result));
+        *cache = Some(currencies.clone());
+
+        Ok(currencies)
+    }
+
+    /// Drops the cached currencies, the next lookup fetching them again.
+    pub fn clear_currencies(&self) {
+        if let Ok(mut cache) = self.currencies.lock() {
+            *cache = None;
+        }
+    }
+
+    /// The Taurus currency of the fee denom of `chain`.
+    pub fn chain_currency(&self, chain: &Chain) -> Result<CurrencyInfo, anyhow::Error> {
+        Ok(self.currencies()?.for_chain(chain)?.clone())
+    }
+
+    /// Total confirmed balance held in `currency` across all wallets.
+    pub fn balance(&self, currency: &CurrencyInfo) -> Result<Amount, anyhow::Error> {
+        self.wallets()?
+            .result
+            .unwrap_or_default()
+            .iter()
+            .filter(|w| w.currency_info.id() == currency.id())
+            .try_fold(currency.amount(0)?, |total, w| {
+                total.checked_add(&w.total_confirmed()?)
+            })
+    }
+
     pub fn addresses_by_address(&self, wallet: Wallet) -> Result<Addresses, anyhow::Error> {
         let addresses = self.addresses()?;
 
@@ -375,34 +1557,323 @@ impl Taurus {
     }
 
     pub fn request(&self, params: RequestParams) -> Result<RequestResponse, anyhow::Error> {
-        self.post(
+        let response = self.post(
             "/api/rest/v1/requests/outgoing/cosmos/generic_request",
             &params,
-        )
+        )?;
+
+        self.notify_new_request(response)
+    }
+
+    /// Submits the request once its fee is checked against the denoms and
+    /// `max_fee` accepted on `chain`.
+    pub fn request_checked(
+        &self,
+        chain: &Chain,
+        params: RequestParams,
+    ) -> Result<RequestResponse, anyhow::Error> {
+        if params.chain_id != chain.chain_id {
+            bail!(
+                "request for {} checked against {}",
+                params.chain_id,
+                chain.chain_id
+            );
+        }
+
+        let fee = params
+            .fee
+            .parse::<u128>()
+            .map_err(|_| anyhow!("invalid fee {}", params.fee))?;
+        chain.check_fee(&Coin::new(fee, params.fee_denom.as_str())?)?;
+
+        self.request(params)
+    }
+
+    fn add_whitelist(&self, params: WhitelistParams) -> Result<WhitelistResponse, anyhow::Error> {
+        params.validate()?;
+
+        let response: WhitelistResponse =
+            self.post("/api/rest/v1/whitelists/addresses", &params)?;
+        self.notify(&Event::WhitelistPending {
+            id: response.result.id.clone(),
+            address: params.address,
+        });
+
+        Ok(response)
     }
 
     pub fn add_contract_whitelist(
         &self,
         params: WhitelistParams,
     ) -> Result<WhitelistResponse, anyhow::Error> {
-        self.post("/api/rest/v1/whitelists/addresses", &params)
+        if params.address_type != AddressType::Contract {
+            bail!("{} is not a contract whitelist", params.address);
+        }
+
+        self.add_whitelist(params)
     }
 
     pub fn add_addr_whitelist(
         &self,
         params: WhitelistParams,
     ) -> Result<WhitelistResponse, anyhow::Error> {
-        self.post("/api/rest/v1/whitelists/addresses", &params)
+        if params.address_type == AddressType::Contract {
+            bail!("use add_contract_whitelist for contract {}", params.address);
+        }
+
+        self.add_whitelist(params)
+    }
+
+    /// Creates the whitelist entry only if no matching one exists yet,
+    /// returning the existing or the new entry.
+    pub fn ensure_whitelisted(
+        &self,
+        params: WhitelistParams,
+    ) -> Result<WhitelistInfos, anyhow::Error> {
+        if let Some(existing) = self
+            .whitelisted_addresses()?
+            .into_iter()
+            .find(|w| w.matches_params(&params))
+        {
+            log::info!(
+                "address {} already whitelisted ({:?})",
+                params.address,
+                existing.status
+            );
+            return Ok(WhitelistInfos { id: existing.id });
+        }
+
+        Ok(self.add_whitelist(params)?.result)
+    }
+
+    pub fn transactions(
+        &self,
+        filter: &TransactionFilter,
+    ) -> Result<TransactionsResponse, anyhow::Error> {
+        self.get(format!("/api/rest/v1/transactions?{}", filter.to_query()).as_str())
+    }
+
+    /// Fetches every page matching `filter`, ignoring its limit and offset.
+    pub fn all_transactions(
+        &self,
+        filter: &TransactionFilter,
+    ) -> Result<Vec<Transaction>, anyhow::Error> {
+        all_pages(TRANSACTION_PAGE_SIZE, |limit, offset| {
+            self.transactions(&TransactionFilter {
+                limit: Some(limit),
+                offset: Some(offset),
+                ..filter.clone()
+            })
+        })
+    }
+
+    pub fn whitelisted_addresses_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<WhitelistedAddressesResponse, anyhow::Error> {
+        self.get(
+            format!(
+                "/api/rest/v1/whitelists/addresses?limit={}&offset={}",
+                limit, offset
+            )
+            .as_str(),
+        )
+    }
+
+    pub fn whitelisted_addresses(&self) -> Result<Vec<WhitelistedAddress>, anyhow::Error> {
+        all_pages(WHITELIST_PAGE_SIZE, |limit, offset| {
+            self.whitelisted_addresses_page(limit, offset)
+        })
+    }
+
+    pub fn whitelisted_address_by_id(&self, id: u64) -> Result<WhitelistedAddress, anyhow::Error> {
+        let response: WhitelistedAddressResponse =
+            self.get(format!("/api/rest/v1/whitelists/addresses/{}", id).as_str())?;
+
+        Ok(response.result)
+    }
+
+    pub fn find_whitelisted(
+        &self,
+        address: &str,
+        blockchain: &str,
+    ) -> Result<Option<WhitelistedAddress>, anyhow::Error> {
+        Ok(self
+            .whitelisted_addresses()?
+            .into_iter()
+            .find(|w| w.matches(address, blockchain)))
+    }
+
+    /// Returns the whitelist entry for `address`, refusing destinations that
+    /// are missing or not approved yet.
+    pub fn approved_whitelisted(
+        &self,
+        address: &str,
+        blockchain: &str,
+    ) -> Result<WhitelistedAddress, anyhow::Error> {
+        match self.find_whitelisted(address, blockchain)? {
+            Some(whitelisted) if whitelisted.is_approved() => Ok(whitelisted),
+            Some(whitelisted) => bail!(
+                "whitelisted address {} is {:?}, not approved",
+                address,
+                whitelisted.status
+            ),
+            None => bail!("address {} is not whitelisted on {}", address, blockchain),
+        }
+    }
+
+    /// Like `approved_whitelisted`, also refusing destinations with a risk
+    /// score above `max_risk_score`, e.g. `Config::max_risk_score`.
+    pub fn safe_whitelisted(
+        &self,
+        address: &str,
+        blockchain: &str,
+        max_risk_score: Option<f64>,
+    ) -> Result<WhitelistedAddress, anyhow::Error> {
+        let whitelisted = self.approved_whitelisted(address, blockchain)?;
+
+        if let Some(max_risk_score) = max_risk_score {
+            whitelisted.check_risk(max_risk_score)?;
+        }
+
+        Ok(whitelisted)
     }
 
     pub fn ethereum_approve(
         &self,
         params: ApproveParams,
     ) -> Result<RequestResponse, anyhow::Error> {
-        self.post("/api/rest/v1/requests/outgoing/contracts/call", &params)
+        self.contract_call(params)
+    }
+
+    /// Calls any contract function, see `eth::EthCall::build_request`.
+    pub fn contract_call(&self, params: ApproveParams) -> Result<RequestResponse, anyhow::Error> {
+        let response = self.post("/api/rest/v1/requests/outgoing/contracts/call", &params)?;
+
+        self.notify_new_request(response)
+    }
+
+    pub fn requests(&self, filter: &RequestFilter) -> Result<RequestsResponse, anyhow::Error> {
+        self.get(format!("/api/rest/v1/requests?{}", filter.to_query()).as_str())
+    }
+
+    /// Fetches every page matching `filter`, ignoring its limit and offset.
+    pub fn all_requests(&self, filter: &RequestFilter) -> Result<Vec<RequestInfos>, anyhow::Error> {
+        all_pages(REQUEST_PAGE_SIZE, |limit, offset| {
+            self.requests(&RequestFilter {
+                limit: Some(limit),
+                offset: Some(offset),
+                ..filter.clone()
+            })
+        })
     }
 
     pub fn request_by_id(&self, id: u64) -> Result<RequestResponse, anyhow::Error> {
         self.get(format!("/api/rest/v1/requests/{}", id).as_str())
     }
+
+    /// Polls a request, whatever its kind, until it reaches a final status.
+    pub fn wait_for_request(
+        &self,
+        id: u64,
+        timeout: Duration,
+    ) -> Result<RequestInfos, anyhow::Error> {
+        let start = std::time::Instant::now();
+
+        loop {
+            let request = self.request_by_id(id)?.result;
+            if let Some(event) = Event::for_final_request(&request) {
+                self.notify(&event);
+                return Ok(request);
+            }
+
+            if start.elapsed() >= timeout {
+                bail!(
+                    "request {} still {} after {:?}",
+                    id,
+                    request.status,
+                    timeout
+                );
+            }
+
+            std::thread::sleep(REQUEST_POLL_INTERVAL.min(timeout.saturating_sub(start.elapsed())));
+        }
+    }
+
+    pub fn outgoing_request(
+        &self,
+        params: &OutgoingParams,
+    ) -> Result<RequestResponse, anyhow::Error> {
+        if params.amount.parse::<u128>().map_or(true, |a| a == 0) {
+            bail!("invalid transfer amount {}", params.amount);
+        }
+
+        let response = self.post("/api/rest/v1/requests/outgoing", params)?;
+
+        self.notify_new_request(response)
+    }
+
+    /// Sends any supported currency (BTC, ETH, tokens...) from a Taurus
+    /// address to a whitelisted address, `amount` being in currency units,
+    /// e.g. `"0.25"`.
+    pub fn transfer(
+        &self,
+        from_address_id: &str,
+        to_whitelisted_address_id: &str,
+        amount: &str,
+        currency: &CurrencyInfo,
+    ) -> Result<RequestResponse, anyhow::Error> {
+        let amount = currency.parse_amount(amount)?;
+
+        self.outgoing_request(&OutgoingParams {
+            from_address_id: from_address_id.to_string(),
+            to_whitelisted_address_id: to_whitelisted_address_id.to_string(),
+            amount: amount.value.to_string(),
+            currency: Some(currency.id().to_string()),
+            comment: None,
+        })
+    }
+
+    /// Sends ETH from a Taurus address to a whitelisted address.
+    pub fn eth_transfer(
+        &self,
+        from_address_id: &str,
+        to_whitelisted_address_id: &str,
+        amount: &str,
+        currency: &CurrencyInfo,
+    ) -> Result<RequestResponse, anyhow::Error> {
+        if !is_evm(currency.blockchain()) {
+            bail!("{} is not on an EVM blockchain", currency.symbol());
+        }
+        if currency.contract_address().is_some() {
+            bail!("{} is a token, use erc20_transfer", currency.symbol());
+        }
+
+        self.transfer(from_address_id, to_whitelisted_address_id, amount, currency)
+    }
+
+    /// Transfers ERC-20 tokens to `recipient` through a call on the
+    /// whitelisted token contract, `amount` being in token units.
+    pub fn erc20_transfer(
+        &self,
+        from_address_id: &str,
+        token_whitelisted_address_id: &str,
+        recipient: &str,
+        amount: &str,
+        currency: &CurrencyInfo,
+    ) -> Result<RequestResponse, anyhow::Error> {
+        if currency.contract_address().is_none() {
+            bail!("{} is not a token", currency.symbol());
+        }
+
+        let amount = currency.parse_amount(amount)?;
+        let call = crate::eth::EthCall::erc20_transfer(currency.blockchain(), recipient, &amount)?;
+
+        self.contract_call(call.build_request(
+            from_address_id,
+            token_whitelisted_address_id,
+            ContractType::Erc20,
+        )?)
+    }
 }
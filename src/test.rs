@@ -1 +1,18 @@
+mod address;
+mod amount;
+#[cfg(feature = "chrono")]
+mod audit;
+mod auth;
+mod coin;
 mod config;
+mod currency;
+mod date;
+mod deposit;
+mod eth;
+mod lcd;
+mod notify;
+mod request;
+mod rpc;
+mod simulate;
+mod stand_in;
+mod taurus;
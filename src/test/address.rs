@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod test_address {
+    use crate::address::{convert_prefix, decode, encode, validate};
+
+    const KEY: [u8; 20] = [
+        0x5c, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e, 0x6f, 0x70, 0x81, 0x92, 0xa3, 0xb4, 0xc5, 0xd6, 0xe7,
+        0xf8, 0x09, 0x1a, 0x2b, 0x3c,
+    ];
+
+    #[test]
+    pub fn encode_decode() -> Result<(), anyhow::Error> {
+        let address = encode("cosmos", &KEY)?;
+
+        assert!(address.starts_with("cosmos1"));
+        assert_eq!(
+            decode(address.as_str())?,
+            ("cosmos".to_string(), KEY.to_vec())
+        );
+
+        assert!(decode("cosmos1invalid").is_err());
+        assert!(decode("toto").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn convert() -> Result<(), anyhow::Error> {
+        let cosmos = encode("cosmos", &KEY)?;
+        let osmo = convert_prefix(cosmos.as_str(), "osmo")?;
+
+        assert!(osmo.starts_with("osmo1"));
+        assert_eq!(osmo, encode("osmo", &KEY)?);
+        assert_eq!(convert_prefix(osmo.as_str(), "cosmos")?, cosmos);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn validate_prefix() -> Result<(), anyhow::Error> {
+        let address = encode("ki", &KEY)?;
+
+        assert!(validate(address.as_str(), "ki").is_ok());
+        assert!(validate(address.as_str(), "tki").is_err());
+        assert!(validate(encode("ki", &KEY[..10])?.as_str(), "ki").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod test_amount {
+    use crate::amount::Amount;
+    use crate::taurus::WalletInfo;
+
+    #[test]
+    pub fn from_decimal_str() -> Result<(), anyhow::Error> {
+        assert_eq!(Amount::from_decimal_str("1.5", 6, "ATOM")?.value, 1_500_000);
+        assert_eq!(Amount::from_decimal_str("0.000001", 6, "ATOM")?.value, 1);
+        assert_eq!(Amount::from_decimal_str(".25", 2, "EUR")?.value, 25);
+        assert_eq!(Amount::from_decimal_str("12", 0, "SAT")?.value, 12);
+        assert_eq!(Amount::from_decimal_str("1.10000000", 2, "EUR")?.value, 110);
+
+        assert!(Amount::from_decimal_str("0.0000001", 6, "ATOM").is_err());
+        assert!(Amount::from_decimal_str("1,5", 6, "ATOM").is_err());
+        assert!(Amount::from_decimal_str("-1", 6, "ATOM").is_err());
+        assert!(Amount::from_decimal_str(".", 6, "ATOM").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn to_decimal_string() {
+        assert_eq!(Amount::new(1_500_000, 6, "ATOM").to_decimal_string(), "1.5");
+        assert_eq!(Amount::new(1, 6, "ATOM").to_decimal_string(), "0.000001");
+        assert_eq!(Amount::new(2_000_000, 6, "ATOM").to_decimal_string(), "2");
+        assert_eq!(Amount::new(0, 18, "ETH").to_decimal_string(), "0");
+        assert_eq!(Amount::new(42, 0, "SAT").to_string(), "42 SAT");
+    }
+
+    #[test]
+    pub fn round_trip() -> Result<(), anyhow::Error> {
+        for value in ["0.1", "123456789.123456789123456789", "1"] {
+            assert_eq!(
+                Amount::from_decimal_str(value, 18, "ETH")?.to_decimal_string(),
+                value
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn wallet_totals() -> Result<(), anyhow::Error> {
+        let wallet: WalletInfo = serde_json::from_str(
+            r#"{
+                "id": "1",
+                "balance": {
+                    "totalConfirmed": "12500000",
+                    "totalUnconfirmed": "0",
+                    "availableConfirmed": "10000000",
+                    "availableUnconfirmed": "0",
+                    "reservedConfirmed": "2500000",
+                    "reservedUnconfirmed": "0"
+                },
+                "currency": "ATOM",
+                "coin": "ATOM",
+                "name": "wallet",
+                "container": null,
+                "accountPath": "m/44'/118'/0'",
+                "isOmnibus": false,
+                "creationDate": "2023-01-01T00:00:00Z",
+                "updateDate": "2023-01-01T00:00:00Z",
+                "blockchain": "COSMOS",
+                "currencyInfo": {
+                    "name": "Cosmos",
+                    "symbol": "ATOM",
+                    "blockchain": "COSMOS",
+                    "decimals": "6",
+                    "contractAddress": null,
+                    "isUTXOBased": false,
+                    "enabled": true,
+                    "id": "ATOM",
+                    "displayName": "Cosmos Atom",
+                    "type": "native"
+                }
+            }"#,
+        )?;
+
+        assert_eq!(wallet.total_confirmed()?.to_string(), "12.5 ATOM");
+        assert_eq!(wallet.available_confirmed()?.to_decimal_string(), "10");
+        assert_eq!(wallet.reserved_confirmed()?.value, 2_500_000);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod test_audit {
+    use crate::audit::{audit_events, fetch, write_csv, write_json_lines};
+    use crate::taurus::{RequestFilter, RequestsResponse};
+    use crate::test::stand_in::{stand_in, taurus};
+    use chrono::{Duration, TimeZone, Utc};
+
+    const REQUESTS: &str = r#"{
+        "result": [
+            {
+                "id": "42",
+                "tenantId": "1",
+                "currency": "ATOM",
+                "envelope": "",
+                "status": "CONFIRMED",
+                "type": "cosmos_generic",
+                "trails": [
+                    {
+                        "userId": "3",
+                        "externalUserId": "bob@taurus.io",
+                        "action": "APPROVE",
+                        "date": "2023-03-02T10:00:00.000Z",
+                        "requestStatus": "APPROVED"
+                    },
+                    {
+                        "userId": "2",
+                        "externalUserId": "alice@taurus.io",
+                        "action": "CREATE",
+                        "date": "2023-03-01T09:30:00+01:00",
+                        "requestStatus": "CREATED"
+                    },
+                    {
+                        "userId": "0",
+                        "externalUserId": "",
+                        "action": "BROADCAST",
+                        "date": null,
+                        "requestStatus": "BROADCASTED"
+                    }
+                ]
+            }
+        ],
+        "totalItems": "1"
+    }"#;
+
+    #[test]
+    pub fn events() -> Result<(), anyhow::Error> {
+        let requests: RequestsResponse = serde_json::from_str(REQUESTS)?;
+        let events = audit_events(&requests.result.unwrap_or_default());
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].action, "CREATE");
+        assert_eq!(
+            events[0].date,
+            Some(Utc.with_ymd_and_hms(2023, 3, 1, 8, 30, 0).unwrap())
+        );
+        assert_eq!(events[1].action, "APPROVE");
+        assert_eq!(events[2].date, None);
+        assert!(events.iter().all(|e| e.request_id == "42"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn export() -> Result<(), anyhow::Error> {
+        let requests: RequestsResponse = serde_json::from_str(REQUESTS)?;
+        let events = audit_events(&requests.result.unwrap_or_default());
+
+        let mut csv = Vec::new();
+        write_csv(&events, &mut csv)?;
+        let csv = String::from_utf8(csv)?;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "date,request_id,request_type,currency,user_id,external_user_id,action,request_status"
+        );
+        assert_eq!(
+            lines[1],
+            "2023-03-01T08:30:00Z,42,cosmos_generic,ATOM,2,alice@taurus.io,CREATE,CREATED"
+        );
+        assert_eq!(lines.len(), 4);
+
+        let mut json = Vec::new();
+        write_json_lines(&events, &mut json)?;
+        let json = String::from_utf8(json)?;
+        let first: serde_json::Value = serde_json::from_str(json.lines().next().unwrap())?;
+        assert_eq!(first["date"], "2023-03-01T08:30:00Z");
+        assert_eq!(first["external_user_id"], "alice@taurus.io");
+        assert_eq!(json.lines().count(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn request_filter() -> Result<(), anyhow::Error> {
+        let filter = RequestFilter {
+            from: Some("2023-03-01T00:00:00.000Z".to_string()),
+            to: Some("2023-04-01T00:00:00.000Z".to_string()),
+            limit: Some(100),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            filter.to_query(),
+            "from=2023-03-01T00%3A00%3A00.000Z&to=2023-04-01T00%3A00%3A00.000Z&limit=100"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn fetch_range() -> Result<(), anyhow::Error> {
+        let (address, handle) = stand_in(&[("200 OK", REQUESTS)]);
+        let from = Utc.with_ymd_and_hms(2023, 3, 2, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2023, 3, 3, 0, 0, 0).unwrap();
+
+        let audit = fetch(&taurus(address.as_str())?, from, to, Duration::days(7))?;
+
+        // requests created up to a week before the range are searched
+        let received = handle.join().unwrap();
+        assert!(received[0]
+            .path
+            .contains("from=2023-02-23T00%3A00%3A00.000Z&to=2023-03-03T00%3A00%3A00.000Z"));
+
+        // the creation is before the range, the approval in it
+        assert_eq!(audit.events.len(), 1);
+        assert_eq!(audit.events[0].action, "APPROVE");
+        assert_eq!(audit.undated.len(), 1);
+        assert_eq!(audit.undated[0].action, "BROADCAST");
+
+        assert!(fetch(&taurus(address.as_str())?, to, from, Duration::days(7)).is_err());
+        assert!(fetch(&taurus(address.as_str())?, from, to, Duration::days(-1)).is_err());
+
+        Ok(())
+    }
+}
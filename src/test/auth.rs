@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod test_auth {
+    use crate::auth::{ApiKeyAuth, Auth, BearerAuth, NoAuth, SignedRequest};
+    use crate::config::{AuthMode, Taurus as TaurusConfig};
+    use crate::taurus::Taurus;
+    use crate::test::stand_in::{stand_in, taurus};
+
+    const SECRET: &str = "00112233445566778899aabbccddeeff";
+
+    fn request() -> SignedRequest<'static> {
+        SignedRequest {
+            method: "POST",
+            host: "api.taurus.io",
+            path: "/api/rest/v1/requests/outgoing",
+            query: "",
+            content_type: "application/json",
+            body: r#"{"amount":"1"}"#,
+        }
+    }
+
+    #[test]
+    pub fn sign() -> Result<(), anyhow::Error> {
+        let auth = ApiKeyAuth::new("k-123", SECRET)?;
+
+        assert_eq!(
+            auth.sign(&request(), "nonce-1", 1_700_000_000_000)?,
+            "TPV1-HMAC-SHA256 ApiKey=k-123 Nonce=nonce-1 Timestamp=1700000000000 \
+             Signature=YFMVGo8HaNHtRWJ4Nctk5cYRE0ZxRUuQGcYOHHg4o2Q="
+        );
+
+        // every request gets a fresh nonce
+        let first = auth.authorization(&request())?.unwrap();
+        let second = auth.authorization(&request())?.unwrap();
+        assert!(first.starts_with("TPV1-HMAC-SHA256 ApiKey=k-123 Nonce="));
+        assert_ne!(first, second);
+
+        assert!(ApiKeyAuth::new("", SECRET).is_err());
+        assert!(ApiKeyAuth::new("k-123", "not hex").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn strategies() -> Result<(), anyhow::Error> {
+        assert_eq!(NoAuth.authorization(&request())?, None);
+        assert!(NoAuth.is_anonymous());
+        assert_eq!(
+            BearerAuth::new("jwt").authorization(&request())?,
+            Some("Bearer jwt".to_string())
+        );
+
+        let cfg: TaurusConfig = toml::from_str(
+            r#"
+            api_url = "https://api.taurus.io"
+            auth = "api_key"
+            api_key = "k-123"
+            api_secret = "00112233445566778899aabbccddeeff"
+            "#,
+        )?;
+        assert_eq!(cfg.auth, AuthMode::ApiKey);
+
+        let cfg: TaurusConfig = toml::from_str(
+            r#"
+            api_url = "https://api.taurus.io"
+            mail = "taurus@taurus.io"
+            passwd = "password"
+            "#,
+        )?;
+        assert_eq!(cfg.auth, AuthMode::Password);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn signed_requests() -> Result<(), anyhow::Error> {
+        let (address, handle) = stand_in(&[(
+            "200 OK",
+            r#"{"name":"taurus","version":"3.1.0","runtime_environment":"test","id":"1","commit":"abc"}"#,
+        )]);
+        let taurus = taurus(address.as_str())?;
+
+        assert_eq!(taurus.node_info()?.version, "3.1.0");
+        assert!(handle.join().unwrap()[0]
+            .authorization
+            .as_ref()
+            .is_some_and(|a| a.starts_with("TPV1-HMAC-SHA256 ApiKey=k-123 ")));
+
+        let missing_secret = Taurus::new(&TaurusConfig {
+            api_url: "https://api.taurus.io".to_string(),
+            mail: String::new(),
+            passwd: String::new(),
+            auth: AuthMode::ApiKey,
+            api_key: Some("k-123".to_string()),
+            api_secret: None,
+        });
+        assert!(missing_secret.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn rejected() -> Result<(), anyhow::Error> {
+        // an error status fails instead of panicking on the payload
+        let (address, handle) =
+            stand_in(&[("401 Unauthorized", r#"{"error":"invalid signature"}"#)]);
+        let error = taurus(address.as_str())?.wallets().unwrap_err();
+        handle.join().unwrap();
+        assert!(error.to_string().contains("401"));
+
+        // so does an unexpected payload
+        let (address, handle) = stand_in(&[("200 OK", "[]")]);
+        assert!(taurus(address.as_str())?.wallets().is_err());
+        handle.join().unwrap();
+
+        Ok(())
+    }
+}
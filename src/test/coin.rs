@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod test_coin {
+    use crate::coin::{validate_denom, Coin, Coins};
+    use std::str::FromStr;
+
+    #[test]
+    pub fn parse_coin() -> Result<(), anyhow::Error> {
+        let coin = Coin::from_str("1000uatom")?;
+
+        assert_eq!(coin.amount, 1000);
+        assert_eq!(coin.denom, "uatom");
+        assert_eq!(coin.to_string(), "1000uatom");
+
+        let coin = Coin::from_str(
+            "5ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+        )?;
+        assert_eq!(coin.amount, 5);
+
+        assert!(Coin::from_str("uatom").is_err());
+        assert!(Coin::from_str("1000").is_err());
+        assert!(Coin::from_str("1000u").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn denom_validation() {
+        assert!(validate_denom("uatom").is_ok());
+        assert!(validate_denom("factory/osmo1abc/token").is_ok());
+        assert!(validate_denom("ua").is_err());
+        assert!(validate_denom("1uatom").is_err());
+        assert!(validate_denom("u atom").is_err());
+    }
+
+    #[test]
+    pub fn coin_arithmetic() -> Result<(), anyhow::Error> {
+        let a = Coin::new(10, "uatom")?;
+        let b = Coin::new(3, "uatom")?;
+
+        assert_eq!(a.checked_add(&b)?.amount, 13);
+        assert_eq!(a.checked_sub(&b)?.amount, 7);
+        assert!(b.checked_sub(&a).is_err());
+        assert!(a.checked_add(&Coin::new(1, "uosmo")?).is_err());
+        assert!(Coin::new(u128::MAX, "uatom")?.checked_add(&b).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn parse_coins() -> Result<(), anyhow::Error> {
+        let coins = Coins::from_str("10uosmo,5uatom,3uosmo")?;
+
+        assert_eq!(coins.len(), 2);
+        assert_eq!(coins.amount_of("uatom"), 5);
+        assert_eq!(coins.amount_of("uosmo"), 13);
+        assert_eq!(coins.to_string(), "5uatom,13uosmo");
+
+        assert!(Coins::from_str("")?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn coins_arithmetic() -> Result<(), anyhow::Error> {
+        let a = Coins::from_str("10uatom,5uosmo")?;
+        let b = Coins::from_str("10uatom,1ujuno")?;
+
+        assert_eq!(a.checked_add(&b)?.to_string(), "20uatom,1ujuno,5uosmo");
+        assert_eq!(
+            a.checked_sub(&Coins::from_str("10uatom")?)?.to_string(),
+            "5uosmo"
+        );
+        assert!(a.checked_sub(&b).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn coins_serde() -> Result<(), anyhow::Error> {
+        let coins = Coins::from_str("5uatom,13uosmo")?;
+        let json = serde_json::to_string(&coins)?;
+
+        assert_eq!(
+            json,
+            r#"[{"denom":"uatom","amount":"5"},{"denom":"uosmo","amount":"13"}]"#
+        );
+        assert_eq!(serde_json::from_str::<Coins>(&json)?, coins);
+        assert!(serde_json::from_str::<Coins>(r#"[{"denom":"1x","amount":"5"}]"#).is_err());
+
+        assert_eq!(
+            serde_json::from_str::<Coin>(r#"{"denom":"uatom","amount":"5"}"#)?,
+            Coin::new(5, "uatom")?
+        );
+        assert!(serde_json::from_str::<Coin>(r#"{"denom":"1 bad","amount":"5"}"#).is_err());
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod test_config {
+    use crate::coin::Coin;
     use crate::config::{Chain, Config, Wallet};
 
     #[test]
@@ -50,7 +51,8 @@ mod test_config {
                 denom: "utki".to_string(),
                 lcd: "https://api-challenge.blockchain.ki".to_string(),
                 rpc: "https://rpc-challenge.blockchain.ki".to_string(),
-                chain_id: "kichain-t-4".to_string()
+                chain_id: "kichain-t-4".to_string(),
+                ..Default::default()
             }
         );
 
@@ -59,4 +61,89 @@ mod test_config {
 
         Ok(())
     }
+
+    #[test]
+    pub fn validate() -> Result<(), anyhow::Error> {
+        let mut cfg = Config::load()?;
+        let key = [7u8; 20];
+
+        cfg.wallet = vec![
+            Wallet {
+                name: "main".to_string(),
+                address: crate::address::encode("tki", &key)?,
+            },
+            Wallet {
+                name: "other".to_string(),
+                address: crate::address::encode("xki", &key)?,
+            },
+        ];
+        assert!(cfg.validate().is_ok());
+
+        cfg.wallet.push(Wallet {
+            name: "wrong".to_string(),
+            address: crate::address::encode("osmo", &key)?,
+        });
+        assert!(cfg.validate().is_err());
+
+        let chain = cfg.find_chain(&"kichain-2".to_string())?;
+        assert_eq!(
+            chain.convert_address(cfg.wallet[0].address.as_str())?,
+            cfg.wallet[1].address
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn fee_policy() -> Result<(), anyhow::Error> {
+        let cfg = Config::load()?;
+        let mut chain = cfg.find_chain(&"kichain-t-4".to_string())?;
+
+        assert!(chain.check_fee(&Coin::new(1_000_000, "utki")?).is_ok());
+        assert!(chain.check_fee(&Coin::new(1, "uxki")?).is_err());
+
+        chain.fee_denoms = Some(vec!["utki".to_string(), "uxki".to_string()]);
+        chain.max_fee = Some("5000utki".to_string());
+        assert!(chain.check_fee(&Coin::new(5000, "utki")?).is_ok());
+        assert!(chain.check_fee(&Coin::new(5001, "utki")?).is_err());
+        assert!(chain.check_fee(&Coin::new(1, "uxki")?).is_err());
+
+        chain.max_fee = Some("5000utki,10uxki".to_string());
+        assert!(chain.check_fee(&Coin::new(10, "uxki")?).is_ok());
+
+        assert_eq!(
+            chain.gas_adjustment(),
+            crate::simulate::DEFAULT_GAS_ADJUSTMENT
+        );
+        chain.gas_adjustment = Some(1.5);
+        assert_eq!(chain.gas_adjustment(), 1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn chain_policy_from_toml() -> Result<(), anyhow::Error> {
+        let chain: Chain = toml::from_str(
+            r#"
+            prefix = "cosmos"
+            denom = "uatom"
+            lcd = "https://lcd"
+            rpc = "https://rpc"
+            chain_id = "cosmoshub-4"
+            gas_price = "0.025"
+            gas_adjustment = 1.4
+            default_gas_limit = 250000
+            max_fee = "100000uatom"
+            fee_denoms = ["uatom"]
+            broadcast_kind = "sync"
+            "#,
+        )?;
+
+        assert_eq!(chain.default_gas_limit, Some(250000));
+        assert_eq!(chain.gas_adjustment(), 1.4);
+        assert_eq!(chain.fee_denoms(), vec!["uatom".to_string()]);
+        assert_eq!(chain.max_fee()?.unwrap().amount_of("uatom"), 100000);
+
+        Ok(())
+    }
 }
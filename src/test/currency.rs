@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod test_currency {
+    use crate::config::Chain;
+    use crate::currency::Currencies;
+    use crate::taurus::CurrenciesResponse;
+    use crate::test::stand_in::{stand_in, taurus};
+    use std::sync::Arc;
+
+    const CURRENCIES: &str = r#"{
+        "result": [
+            {
+                "id": "ATOM",
+                "name": "Cosmos",
+                "symbol": "ATOM",
+                "blockchain": "COSMOS",
+                "decimals": "6",
+                "contractAddress": null,
+                "isUTXOBased": false,
+                "enabled": true,
+                "displayName": "Cosmos Atom",
+                "type": "native"
+            },
+            {
+                "id": "ETH",
+                "name": "Ethereum",
+                "symbol": "ETH",
+                "blockchain": "ETH",
+                "decimals": "18",
+                "contractAddress": null,
+                "isUTXOBased": false,
+                "enabled": true,
+                "displayName": "Ether",
+                "type": "native"
+            },
+            {
+                "id": "USDC",
+                "name": "USD Coin",
+                "symbol": "USDC",
+                "blockchain": "ETH",
+                "decimals": "6",
+                "contractAddress": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+                "isUTXOBased": false,
+                "enabled": true,
+                "displayName": "USD Coin",
+                "type": "token"
+            },
+            {
+                "id": "OSMO_ATOM",
+                "name": "Cosmos on Osmosis",
+                "symbol": "ATOM",
+                "blockchain": "OSMOSIS",
+                "decimals": "6",
+                "contractAddress": "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+                "isUTXOBased": false,
+                "enabled": true,
+                "displayName": "Atom (Osmosis)",
+                "type": "token"
+            }
+        ]
+    }"#;
+
+    fn currencies() -> Result<Currencies, anyhow::Error> {
+        let response: CurrenciesResponse = serde_json::from_str(CURRENCIES)?;
+
+        Ok(Currencies::new(response.result))
+    }
+
+    fn chain(denom: &str) -> Chain {
+        Chain {
+            denom: denom.to_string(),
+            chain_id: "test-1".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    pub fn lookups() -> Result<(), anyhow::Error> {
+        let currencies = currencies()?;
+
+        assert_eq!(currencies.len(), 4);
+        assert_eq!(currencies.by_id("ETH").map(|c| c.symbol()), Some("ETH"));
+        assert_eq!(currencies.by_symbol("atom").len(), 2);
+        assert_eq!(
+            currencies
+                .by_contract("eth", "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+                .map(|c| c.id()),
+            Some("USDC")
+        );
+        assert_eq!(
+            currencies.get("ATOM", "COSMOS", None).map(|c| c.id()),
+            Some("ATOM")
+        );
+        assert_eq!(
+            currencies
+                .get(
+                    "USDC",
+                    "ETH",
+                    Some("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")
+                )
+                .map(|c| c.id()),
+            Some("USDC")
+        );
+        assert!(currencies.get("USDC", "ETH", None).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn for_chain() -> Result<(), anyhow::Error> {
+        let currencies = currencies()?;
+
+        assert_eq!(currencies.for_chain(&chain("uatom"))?.id(), "ATOM");
+        assert_eq!(currencies.for_chain(&chain("aeth"))?.id(), "ETH");
+        assert_eq!(
+            currencies
+                .for_chain(&chain(
+                    "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+                ))?
+                .id(),
+            "OSMO_ATOM"
+        );
+        assert!(currencies.for_chain(&chain("uosmo")).is_err());
+        // ETH has 18 decimals, not 6
+        assert!(currencies.for_chain(&chain("ueth")).is_err());
+
+        let chain = Chain {
+            currency_id: Some("USDC".to_string()),
+            ..chain("uusdc")
+        };
+        assert_eq!(currencies.for_chain(&chain)?.id(), "USDC");
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn cache() -> Result<(), anyhow::Error> {
+        let (address, handle) = stand_in(&[("200 OK", CURRENCIES), ("200 OK", CURRENCIES)]);
+        let taurus = taurus(address.as_str())?;
+
+        // fetched once, then shared
+        let first = taurus.currencies()?;
+        assert!(Arc::ptr_eq(&first, &taurus.currencies()?));
+        assert_eq!(taurus.chain_currency(&chain("uatom"))?.id(), "ATOM");
+
+        taurus.clear_currencies();
+        assert!(!Arc::ptr_eq(&first, &taurus.currencies()?));
+        assert_eq!(handle.join().unwrap().len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test_date {
+    use crate::date::parse;
+    use crate::taurus::{SignedRequests, Trails};
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn formats() -> Result<(), anyhow::Error> {
+        use chrono::{TimeZone, Utc};
+
+        let expected = Utc.with_ymd_and_hms(2023, 3, 1, 8, 30, 0).unwrap();
+
+        assert_eq!(parse("2023-03-01T08:30:00Z")?, expected);
+        assert_eq!(parse("2023-03-01T09:30:00+01:00")?, expected);
+        assert_eq!(parse("2023-03-01 08:30:00")?, expected);
+        assert_eq!(parse("2023-03-01T08:30:00.000")?, expected);
+        assert!(parse("yesterday").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn optional() -> Result<(), anyhow::Error> {
+        for date in [r#""date": null,"#, r#""date": "","#, ""] {
+            let trail: Trails = serde_json::from_str(
+                format!(
+                    r#"{{
+                        "userId": "2",
+                        "externalUserId": "alice@taurus.io",
+                        "action": "CREATE",
+                        {}
+                        "requestStatus": "CREATED"
+                    }}"#,
+                    date
+                )
+                .as_str(),
+            )?;
+            assert_eq!(trail.date, None);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn malformed() -> Result<(), anyhow::Error> {
+        let signed = |creation_date: &str| {
+            serde_json::from_str::<SignedRequests>(
+                serde_json::json!({
+                    "id": "1",
+                    "signedRequest": "",
+                    "status": "SIGNED",
+                    "creationDate": creation_date,
+                    "updateDate": "2023-03-01T08:00:00.000Z"
+                })
+                .to_string()
+                .as_str(),
+            )
+        };
+
+        // a bad date fails rather than passing for a missing one
+        assert!(signed("yesterday").is_err());
+
+        let signed = signed("")?;
+        assert_eq!(signed.creation_date, None);
+        assert_eq!(signed.update_date, Some(parse("2023-03-01T08:00:00Z")?));
+
+        // the request dates are still required
+        let missing: Result<SignedRequests, _> =
+            serde_json::from_str(r#"{"id": "1", "signedRequest": "", "status": "SIGNED"}"#);
+        assert!(missing.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn order() -> Result<(), anyhow::Error> {
+        let signed = |id: &str, date: &str| -> Result<SignedRequests, anyhow::Error> {
+            Ok(serde_json::from_str(
+                serde_json::json!({
+                    "id": id,
+                    "signedRequest": "",
+                    "status": "SIGNED",
+                    "creationDate": date,
+                    "updateDate": date
+                })
+                .to_string()
+                .as_str(),
+            )?)
+        };
+
+        let requests = [
+            signed("2", "2023-03-02T08:00:00.000Z")?,
+            signed("1", "2023-03-01T08:00:00.000Z")?,
+        ];
+        let first = requests.iter().min_by_key(|r| &r.creation_date).unwrap();
+
+        assert_eq!(first.id, "1");
+        assert_eq!(
+            first.creation_date,
+            Some(parse("2023-03-01T08:00:00.000Z")?)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod test_deposit {
+    use crate::date::parse;
+    use crate::deposit::{DepositCursor, DepositWatcher};
+    use crate::taurus::{
+        Transaction, TransactionDirection, TransactionFilter, TransactionsResponse,
+    };
+
+    fn transaction(id: u64, direction: &str, confirmed: bool) -> Transaction {
+        let json = serde_json::json!({
+            "receptionDate": format!("2023-01-01T00:00:{:02}Z", id),
+            "id": id.to_string(),
+            "direction": direction,
+            "currency": "ETH",
+            "blockchain": "ETH",
+            "hash": format!("0x{:064x}", id),
+            "block": "100",
+            "amount": "1000",
+            "isConfirmed": confirmed,
+            "destinations": [{ "address": "0xabc", "amount": "1000" }]
+        });
+
+        serde_json::from_str(json.to_string().as_str()).unwrap()
+    }
+
+    #[test]
+    pub fn transactions_response() -> Result<(), anyhow::Error> {
+        let response: TransactionsResponse = serde_json::from_str(
+            r#"{
+                "result": [
+                    {
+                        "id": "42",
+                        "direction": "incoming",
+                        "currency": "BTC",
+                        "hash": "abc",
+                        "amount": "150000",
+                        "fee": "300",
+                        "sources": [{ "address": "bc1src" }],
+                        "destinations": [{ "address": "bc1dst", "amount": "150000" }],
+                        "isConfirmed": true,
+                        "receptionDate": "2023-01-01T00:00:00Z",
+                        "confirmationDate": "2023-01-01T00:10:00Z"
+                    },
+                    {
+                        "id": "43",
+                        "direction": "internal",
+                        "currency": "BTC",
+                        "hash": "def",
+                        "amount": "1"
+                    }
+                ],
+                "totalItems": "2"
+            }"#,
+        )?;
+
+        let transactions = response.result.unwrap();
+        assert!(transactions[0].is_incoming());
+        assert!(transactions[0].is_confirmed());
+        assert!(transactions[0].is_to("bc1dst"));
+        assert_eq!(transactions[0].fee, Some(300));
+        assert_eq!(transactions[1].direction, TransactionDirection::Unknown);
+        assert!(!transactions[1].is_confirmed());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn filter_query() {
+        let filter = TransactionFilter {
+            currency: Some("ETH".to_string()),
+            direction: Some(TransactionDirection::Incoming),
+            from: Some("2023-01-01T00:00:00+01:00".to_string()),
+            limit: Some(50),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            filter.to_query(),
+            "currency=ETH&direction=incoming&from=2023-01-01T00%3A00%3A00%2B01%3A00&limit=50"
+        );
+        assert_eq!(TransactionFilter::default().to_query(), "");
+    }
+
+    #[test]
+    pub fn watcher() -> Result<(), anyhow::Error> {
+        let mut watcher = DepositWatcher::new(
+            TransactionFilter::default(),
+            DepositCursor {
+                last_seen: Some(1),
+                ..Default::default()
+            },
+        );
+
+        let deposits = watcher.process(vec![
+            transaction(3, "incoming", false),
+            transaction(1, "incoming", true),
+            transaction(2, "incoming", true),
+            transaction(4, "outgoing", true),
+        ])?;
+        assert_eq!(deposits.len(), 1);
+        assert_eq!(deposits[0].id, "2");
+        assert_eq!(watcher.last_seen(), Some(3));
+        assert!(watcher.pending().contains(&3));
+        // next polls start from the pending deposit
+        assert_eq!(watcher.cursor().since, Some(parse("2023-01-01T00:00:03Z")?));
+
+        // nothing new
+        let deposits = watcher.process(vec![
+            transaction(2, "incoming", true),
+            transaction(3, "incoming", false),
+        ])?;
+        assert!(deposits.is_empty());
+
+        // pending deposit confirmed along with a new one
+        let deposits = watcher.process(vec![
+            transaction(2, "incoming", true),
+            transaction(3, "incoming", true),
+            transaction(5, "incoming", true),
+        ])?;
+        let ids: Vec<&str> = deposits.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["3", "5"]);
+        assert!(watcher.pending().is_empty());
+        assert_eq!(watcher.last_seen(), Some(5));
+        assert_eq!(watcher.cursor().since, Some(parse("2023-01-01T00:00:05Z")?));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn restart() -> Result<(), anyhow::Error> {
+        let mut watcher = DepositWatcher::new(TransactionFilter::default(), Default::default());
+
+        let deposits = watcher.process(vec![
+            transaction(1, "incoming", false),
+            transaction(2, "incoming", true),
+        ])?;
+        assert_eq!(deposits.len(), 1);
+
+        // the cursor is persisted then the watcher restarted
+        let cursor: DepositCursor =
+            serde_json::from_str(serde_json::to_string(watcher.cursor())?.as_str())?;
+        assert_eq!(&cursor, watcher.cursor());
+        let mut watcher = DepositWatcher::new(TransactionFilter::default(), cursor);
+
+        // the pending deposit is yielded once confirmed, the other not twice
+        let deposits = watcher.process(vec![
+            transaction(1, "incoming", true),
+            transaction(2, "incoming", true),
+        ])?;
+        let ids: Vec<&str> = deposits.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["1"]);
+        assert!(watcher.pending().is_empty());
+
+        Ok(())
+    }
+}
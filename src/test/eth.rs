@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod test_eth {
+    use crate::amount::Amount;
+    use crate::eth::{encode_args, encode_hex, AbiType, EthCall, FunctionSignature};
+    use crate::taurus::ContractType;
+    use std::str::FromStr;
+
+    const TO: &str = "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984";
+
+    #[test]
+    pub fn parse_signature() -> Result<(), anyhow::Error> {
+        let signature = FunctionSignature::from_str("transfer(address to, uint amount)")?;
+        assert_eq!(signature.to_string(), "transfer(address,uint256)");
+        assert_eq!(encode_hex(&signature.selector()), "0xa9059cbb");
+
+        let signature = FunctionSignature::from_str("approve(address,uint256)")?;
+        assert_eq!(encode_hex(&signature.selector()), "0x095ea7b3");
+
+        let signature = FunctionSignature::from_str("f(bytes32[2],string,bool[],int8)")?;
+        assert_eq!(
+            signature.inputs,
+            vec![
+                AbiType::FixedArray(Box::new(AbiType::FixedBytes(32)), 2),
+                AbiType::String,
+                AbiType::Array(Box::new(AbiType::Bool)),
+                AbiType::Int(8),
+            ]
+        );
+
+        assert!(FunctionSignature::from_str("transfer(address,uint7)").is_err());
+        assert!(FunctionSignature::from_str("transfer(address").is_err());
+        assert!(FunctionSignature::from_str("f((address,uint256))").is_err());
+        assert!(FunctionSignature::from_str("f(bytes33)").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn encode_transfer() -> Result<(), anyhow::Error> {
+        let call = EthCall::erc20_transfer("ETH", TO, &Amount::new(1000, 18, "UNI"))?;
+
+        assert_eq!(
+            encode_hex(&call.encode()?),
+            "0xa9059cbb\
+             0000000000000000000000001f9840a85d5af5bf1d1762f925bdaddc4201f984\
+             00000000000000000000000000000000000000000000000000000000000003e8"
+        );
+
+        let params = call.build_request("12", "34", ContractType::Erc20)?;
+        assert_eq!(
+            serde_json::to_value(&params)?,
+            serde_json::json!({
+                "fromAddressId": "12",
+                "toWhitelistedAddressId": "34",
+                "contractType": "ERC20",
+                "call": {
+                    "blockchain": "ETH",
+                    "eth": {
+                        "functionSignature": "transfer(address,uint256)",
+                        "args": [
+                            { "name": "to", "type": "address", "value": { "primitive": TO } },
+                            { "name": "amount", "type": "uint256", "value": { "primitive": "1000" } }
+                        ]
+                    }
+                }
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn validate_args() -> Result<(), anyhow::Error> {
+        let call = EthCall::new("ETH", "f(address,uint8,int8,bool,bytes2)")?;
+
+        assert!(call.clone().arg("a", "0x1234").is_err());
+        // non-ASCII input is rejected, not sliced
+        assert!(call.clone().arg("a", "0xaé1").is_err());
+        assert!(call.clone().arg("b", "0xé").is_err());
+        assert!(call.clone().arg("e", "0xéé").is_err());
+        let call = call.arg("a", TO)?;
+        assert!(call.clone().arg("b", "256").is_err());
+        assert!(call.clone().arg("b", "-1").is_err());
+        let call = call.arg("b", "255")?;
+        assert!(call.clone().arg("c", "128").is_err());
+        assert!(call.clone().arg("c", "-129").is_err());
+        let call = call.arg("c", "-128")?;
+        assert!(call.clone().arg("d", "yes").is_err());
+        let call = call.arg("d", "true")?;
+
+        assert!(call.build().is_err());
+        assert!(call.clone().arg("e", "0x123456").is_err());
+        let call = call.arg("e", "0xbeef")?;
+        assert!(call.clone().arg("f", "1").is_err());
+
+        let encoded = call.encode()?;
+        assert_eq!(encoded.len(), 4 + 5 * 32);
+        // int8 -128 is sign extended
+        assert_eq!(encoded[4 + 2 * 32], 0xff);
+        assert_eq!(encoded[4 + 3 * 32 - 1], 0x80);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn padded_args() -> Result<(), anyhow::Error> {
+        let call = EthCall::new("ETH", "f(string,uint256,address)")?
+            .arg("memo", "  padded  ")?
+            .arg("amount", " 5 ")?
+            .arg("to", format!(" {} ", TO).as_str())?;
+
+        // spaces only matter in strings
+        let encoded = call.encode()?;
+        assert_eq!(encoded[4 + 2 * 32 - 1], 5);
+        assert_eq!(encoded[4 + 4 * 32 - 1], 10);
+        assert_eq!(&encoded[4 + 4 * 32..4 + 4 * 32 + 10], b"  padded  ");
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn encode_dynamic() -> Result<(), anyhow::Error> {
+        // example from the Solidity ABI specification
+        let types = vec![
+            AbiType::from_str("uint256")?,
+            AbiType::from_str("uint32[]")?,
+            AbiType::from_str("bytes10")?,
+            AbiType::from_str("bytes")?,
+        ];
+        let values = vec![
+            "0x123".to_string(),
+            "[\"0x456\", \"0x789\"]".to_string(),
+            encode_hex(b"1234567890"),
+            encode_hex(b"Hello, world!"),
+        ];
+
+        assert_eq!(
+            encode_hex(&encode_args(&types, &values)?),
+            "0x\
+             0000000000000000000000000000000000000000000000000000000000000123\
+             0000000000000000000000000000000000000000000000000000000000000080\
+             3132333435363738393000000000000000000000000000000000000000000000\
+             00000000000000000000000000000000000000000000000000000000000000e0\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000456\
+             0000000000000000000000000000000000000000000000000000000000000789\
+             000000000000000000000000000000000000000000000000000000000000000d\
+             48656c6c6f2c20776f726c642100000000000000000000000000000000000000"
+        );
+
+        Ok(())
+    }
+}
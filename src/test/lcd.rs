@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod test_lcd {
+    use crate::lcd::{Account, AccountResponse};
+    use crate::taurus::AccountInfo;
+
+    #[test]
+    pub fn base_account() -> Result<(), anyhow::Error> {
+        let response: AccountResponse = serde_json::from_str(
+            r#"{
+                "account": {
+                    "@type": "/cosmos.auth.v1beta1.BaseAccount",
+                    "address": "cosmos1abc",
+                    "pub_key": null,
+                    "account_number": "1234",
+                    "sequence": "56"
+                }
+            }"#,
+        )?;
+
+        assert!(matches!(response.account, Account::Base(_)));
+        assert_eq!(
+            AccountInfo::from(response.account.base_account()),
+            AccountInfo {
+                sequence: "56".to_string(),
+                account_number: "1234".to_string(),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn vesting_account() -> Result<(), anyhow::Error> {
+        let response: AccountResponse = serde_json::from_str(
+            r#"{
+                "account": {
+                    "@type": "/cosmos.vesting.v1beta1.ContinuousVestingAccount",
+                    "base_vesting_account": {
+                        "base_account": {
+                            "address": "cosmos1abc",
+                            "pub_key": null,
+                            "account_number": "7",
+                            "sequence": "8"
+                        },
+                        "original_vesting": [],
+                        "delegated_free": [],
+                        "delegated_vesting": [],
+                        "end_time": "1700000000"
+                    },
+                    "start_time": "1600000000"
+                }
+            }"#,
+        )?;
+
+        assert!(matches!(response.account, Account::ContinuousVesting(_)));
+        assert_eq!(response.account.base_account().account_number, 7);
+        assert_eq!(response.account.base_account().sequence, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn module_account() -> Result<(), anyhow::Error> {
+        let response: AccountResponse = serde_json::from_str(
+            r#"{
+                "account": {
+                    "@type": "/cosmos.auth.v1beta1.ModuleAccount",
+                    "base_account": {
+                        "address": "cosmos1fee",
+                        "pub_key": null,
+                        "account_number": "3",
+                        "sequence": "0"
+                    },
+                    "name": "fee_collector",
+                    "permissions": []
+                }
+            }"#,
+        )?;
+
+        match response.account {
+            Account::Module(ref module) => assert_eq!(module.name, "fee_collector"),
+            _ => panic!("expected module account"),
+        }
+        assert_eq!(response.account.base_account().address, "cosmos1fee");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod test_notify {
+    use crate::config::{Config, NotifierConfig};
+    use crate::notify::{Event, LogNotifier, Notifier, Notifiers, SlackNotifier, WebhookNotifier};
+    use crate::taurus::RequestInfos;
+    use crate::test::stand_in::stand_in;
+    use std::collections::BTreeMap;
+
+    fn request(status: &str) -> RequestInfos {
+        RequestInfos {
+            id: "42".to_string(),
+            currency: "ATOM".to_string(),
+            status: status.to_string(),
+            type_request: "cosmos_generic".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    pub fn events() -> Result<(), anyhow::Error> {
+        let events = Event::for_new_request(&request("CREATED"));
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1], Event::ApprovalNeeded { .. }));
+        assert_eq!(Event::for_new_request(&request("BROADCASTED")).len(), 1);
+
+        assert_eq!(
+            Event::for_final_request(&request("CONFIRMED")),
+            Some(Event::RequestConfirmed {
+                id: "42".to_string()
+            })
+        );
+        assert!(matches!(
+            Event::for_final_request(&request("REJECTED")),
+            Some(Event::RequestRejected { .. })
+        ));
+        assert_eq!(Event::for_final_request(&request("APPROVING")), None);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn slack() -> Result<(), anyhow::Error> {
+        let (url, handle) = stand_in(&[("200 OK", "ok")]);
+        let notifier = SlackNotifier::new(url.as_str())?;
+
+        notifier.send(&Event::WhitelistPending {
+            id: "7".to_string(),
+            address: "0xabc".to_string(),
+        })?;
+
+        let body: serde_json::Value =
+            serde_json::from_str(handle.join().unwrap()[0].body.as_str())?;
+        assert_eq!(
+            body["text"],
+            ":hourglass: Whitelist 7 for 0xabc is pending approval"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn slack_failure() -> Result<(), anyhow::Error> {
+        let (url, handle) = stand_in(&[("500 Internal Server Error", "ok")]);
+        let notifier = SlackNotifier::new(url.as_str())?;
+        let event = Event::RequestConfirmed {
+            id: "42".to_string(),
+        };
+
+        assert!(notifier.send(&event).is_err());
+        handle.join().unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn webhook() -> Result<(), anyhow::Error> {
+        let (url, handle) = stand_in(&[("204 No Content", "ok")]);
+        let notifier = WebhookNotifier::new(url.as_str(), BTreeMap::new())?;
+
+        notifier.send(&Event::RequestRejected {
+            id: "42".to_string(),
+            status: "REJECTED".to_string(),
+        })?;
+
+        let body: serde_json::Value =
+            serde_json::from_str(handle.join().unwrap()[0].body.as_str())?;
+        assert_eq!(body["event"], "request_rejected");
+        assert_eq!(body["id"], "42");
+        assert_eq!(body["status"], "REJECTED");
+        assert_eq!(body["text"], ":x: Request 42 ended as REJECTED");
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn fan_out() -> Result<(), anyhow::Error> {
+        let (first, first_handle) = stand_in(&[("500 Internal Server Error", "ok")]);
+        let (second, second_handle) = stand_in(&[("200 OK", "ok")]);
+
+        let mut notifiers = Notifiers::new();
+        notifiers.add(SlackNotifier::new(first.as_str())?);
+        notifiers.add(LogNotifier::default());
+        notifiers.add(WebhookNotifier::new(second.as_str(), BTreeMap::new())?);
+
+        // the failing first channel doesn't prevent the others from being notified
+        notifiers.notify(&Event::RequestConfirmed {
+            id: "42".to_string(),
+        });
+        first_handle.join().unwrap();
+        assert!(second_handle.join().unwrap()[0]
+            .body
+            .contains("request_confirmed"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn from_config() -> Result<(), anyhow::Error> {
+        let mut config = Config::load()?;
+        config.slack_web_hook = None;
+        config.notifier = Vec::new();
+        assert!(Notifiers::from_config(&config)?.is_empty());
+
+        config.slack_web_hook = Some("https://hooks.slack.com/services/T/B/X".to_string());
+        config.notifier = toml::from_str::<toml::Value>(
+            r#"
+            [[notifier]]
+            kind = "webhook"
+            url = "https://relay.example.com/taurus"
+            headers = { Authorization = "Bearer xxx" }
+
+            [[notifier]]
+            kind = "log"
+            level = "warn"
+            "#,
+        )?["notifier"]
+            .clone()
+            .try_into::<Vec<NotifierConfig>>()?;
+        assert_eq!(Notifiers::from_config(&config)?.len(), 3);
+
+        config.notifier = vec![NotifierConfig::Log {
+            level: Some("loud".to_string()),
+        }];
+        assert!(Notifiers::from_config(&config).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod test_request {
+    use crate::config::{Config, Wallet};
+    use crate::payload::{Coins, Message};
+    use crate::request::RequestBuilder;
+    use crate::taurus::{BroadcastKind, RequestParams};
+    use crate::test::stand_in::{stand_in, taurus};
+    use std::str::FromStr;
+
+    fn config() -> Result<Config, anyhow::Error> {
+        let mut cfg = Config::load()?;
+        cfg.wallet = vec![Wallet {
+            name: "main".to_string(),
+            address: crate::address::encode("tki", &[1u8; 20])?,
+        }];
+        Ok(cfg)
+    }
+
+    fn send(cfg: &Config) -> Message {
+        Message::build_bank_send(
+            cfg.wallet[0].address.clone(),
+            cfg.wallet[0].address.clone(),
+            Coins::new(),
+        )
+    }
+
+    #[test]
+    pub fn for_chain() -> Result<(), anyhow::Error> {
+        let cfg = config()?;
+
+        let builder = RequestBuilder::for_chain(&cfg, "kichain-t-4")?;
+        assert_eq!(builder.chain().denom, "utki");
+
+        assert!(RequestBuilder::for_chain(&cfg, "unknown").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn validate() -> Result<(), anyhow::Error> {
+        let cfg = config()?;
+        let builder = || RequestBuilder::for_chain(&cfg, "kichain-t-4");
+
+        assert!(builder()?.validate().is_err());
+        assert!(builder()?.message(send(&cfg)).validate().is_err());
+        assert!(builder()?
+            .message(send(&cfg))
+            .signer("main")
+            .validate()
+            .is_err());
+        assert!(builder()?
+            .message(send(&cfg))
+            .signer("main")
+            .gas_limit(0)
+            .fee(1000)
+            .validate()
+            .is_err());
+        assert!(builder()?
+            .message(send(&cfg))
+            .signer("unknown")
+            .gas_limit(200000)
+            .fee(1000)
+            .validate()
+            .is_err());
+
+        let wallets = builder()?
+            .message(send(&cfg))
+            .signer("main")
+            .gas_limit(200000)
+            .fee(1000)
+            .validate()?;
+        assert_eq!(wallets, cfg.wallet);
+
+        // wallet address does not belong to the mainnet chain
+        assert!(RequestBuilder::for_chain(&cfg, "kichain-2")?
+            .message(send(&cfg))
+            .signer("main")
+            .gas_limit(200000)
+            .fee(1000)
+            .validate()
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn chain_defaults() -> Result<(), anyhow::Error> {
+        let mut cfg = config()?;
+        cfg.chain[0].default_gas_limit = Some(200000);
+        cfg.chain[0].gas_price = Some("0.025".to_string());
+        cfg.chain[0].max_fee = Some("4000utki".to_string());
+
+        // fee derived from gas price is 5000utki, above max_fee
+        let builder = RequestBuilder::for_chain(&cfg, "kichain-t-4")?
+            .message(send(&cfg))
+            .signer("main");
+        assert!(builder.validate().is_err());
+
+        let builder = RequestBuilder::for_chain(&cfg, "kichain-t-4")?
+            .message(send(&cfg))
+            .signer("main")
+            .gas_limit(100000);
+        assert!(builder.validate().is_ok());
+
+        let builder = RequestBuilder::for_chain(&cfg, "kichain-t-4")?
+            .message(send(&cfg))
+            .signer("main")
+            .fee(1)
+            .fee_denom("uxki");
+        assert!(builder.validate().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn request_checked() -> Result<(), anyhow::Error> {
+        let mut cfg = config()?;
+        cfg.chain[0].max_fee = Some("4000utki".to_string());
+        let chain = &cfg.chain[0];
+        let params = |fee: &str, fee_denom: &str| RequestParams {
+            chain_id: "kichain-t-4".to_string(),
+            fee: fee.to_string(),
+            fee_denom: fee_denom.to_string(),
+            ..Default::default()
+        };
+
+        // rejected before reaching Taurus
+        let offline = taurus("http://127.0.0.1:1")?;
+        assert!(offline
+            .request_checked(chain, params("5000", "utki"))
+            .is_err());
+        assert!(offline.request_checked(chain, params("1", "uxki")).is_err());
+        assert!(offline
+            .request_checked(chain, params("-1", "utki"))
+            .is_err());
+        let other_chain = RequestParams {
+            chain_id: "cosmoshub-4".to_string(),
+            ..params("1", "utki")
+        };
+        assert!(offline.request_checked(chain, other_chain).is_err());
+
+        let (address, handle) = stand_in(&[(
+            "200 OK",
+            r#"{"result":{"id":"7","tenantId":"1","currency":"TKI","envelope":"","status":"CREATED","type":"cosmos","signedRequests":null,"trails":[],"metadata":null}}"#,
+        )]);
+        let response = taurus(address.as_str())?.request_checked(chain, params("4000", "utki"))?;
+        assert_eq!(response.result.id, "7");
+
+        let received = handle.join().unwrap();
+        assert!(received[0].path.ends_with("/cosmos/generic_request"));
+        let body: serde_json::Value = serde_json::from_str(received[0].body.as_str())?;
+        assert_eq!(body["fee"], "4000");
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn broadcast_kind() -> Result<(), anyhow::Error> {
+        assert_eq!(serde_json::to_string(&BroadcastKind::Sync)?, r#""sync""#);
+        assert_eq!(BroadcastKind::from_str("ASYNC")?, BroadcastKind::Async);
+        assert!(BroadcastKind::from_str("blocking").is_err());
+        assert_eq!(BroadcastKind::default().to_string(), "sync");
+
+        let block = BroadcastKind::Block;
+        assert_eq!(block.for_sdk_version(None), BroadcastKind::Block);
+        assert_eq!(block.for_sdk_version(Some("0.46.13")), BroadcastKind::Block);
+        assert_eq!(block.for_sdk_version(Some("v0.47.5")), BroadcastKind::Sync);
+        assert_eq!(block.for_sdk_version(Some("0.50.1")), BroadcastKind::Sync);
+        assert_eq!(
+            BroadcastKind::Async.for_sdk_version(Some("0.50.1")),
+            BroadcastKind::Async
+        );
+
+        let mut cfg = config()?;
+        cfg.chain[0].broadcast_kind = Some(BroadcastKind::Block);
+        cfg.chain[0].sdk_version = Some("0.47.0".to_string());
+        assert_eq!(cfg.chain[0].broadcast_kind(), BroadcastKind::Sync);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod test_rpc {
+    use crate::rpc::{normalize_hash, RpcResponse, TxResponse, TxResult};
+    use crate::taurus::RequestResponse;
+
+    const HASH: &str = "8A4F8FBE4B0A0E1A6F2D4C3B2A19081726354453627180796A5B4C3D2E1F0A9B";
+
+    #[test]
+    pub fn hash() -> Result<(), anyhow::Error> {
+        assert_eq!(normalize_hash(HASH)?, HASH);
+        assert_eq!(
+            normalize_hash(format!("0x{}", HASH.to_lowercase()).as_str())?,
+            HASH
+        );
+        assert!(normalize_hash("0x1234").is_err());
+        assert!(normalize_hash(&HASH.replace('A', "Z")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn tx_result() -> Result<(), anyhow::Error> {
+        let response: RpcResponse<TxResponse> = serde_json::from_str(
+            format!(
+                r#"{{
+                    "jsonrpc": "2.0",
+                    "id": -1,
+                    "result": {{
+                        "hash": "{}",
+                        "height": "1234",
+                        "index": 0,
+                        "tx_result": {{
+                            "code": 5,
+                            "data": null,
+                            "log": "insufficient funds",
+                            "info": "",
+                            "gas_wanted": "200000",
+                            "gas_used": "54321",
+                            "events": [],
+                            "codespace": "sdk"
+                        }},
+                        "tx": ""
+                    }}
+                }}"#,
+                HASH
+            )
+            .as_str(),
+        )?;
+
+        let tx: TxResult = response.result.unwrap().into();
+        assert_eq!(tx.height, 1234);
+        assert_eq!(tx.gas_used, 54321);
+        assert_eq!(tx.codespace, "sdk");
+        assert_eq!(tx.raw_log, "insufficient funds");
+        assert!(!tx.is_success());
+
+        let response: RpcResponse<TxResponse> = serde_json::from_str(
+            r#"{
+                "jsonrpc": "2.0",
+                "id": -1,
+                "error": {
+                    "code": -32603,
+                    "message": "Internal error",
+                    "data": "tx (8A4F) not found"
+                }
+            }"#,
+        )?;
+        assert!(response.result.is_none());
+        assert_eq!(response.error.unwrap().code, -32603);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn request_hashes() -> Result<(), anyhow::Error> {
+        let response: RequestResponse = serde_json::from_str(
+            format!(
+                r#"{{
+                    "result": {{
+                        "id": "42",
+                        "tenantId": "1",
+                        "currency": "ATOM",
+                        "envelope": "",
+                        "status": "CONFIRMED",
+                        "type": "cosmos_generic",
+                        "signedRequests": [
+                            {{
+                                "id": "1",
+                                "signedRequest": "",
+                                "status": "CONFIRMED",
+                                "creationDate": "",
+                                "updateDate": "",
+                                "hash": "{}",
+                                "block": "1234"
+                            }},
+                            {{
+                                "id": "2",
+                                "signedRequest": "",
+                                "status": "SIGNED",
+                                "creationDate": "",
+                                "updateDate": ""
+                            }}
+                        ],
+                        "trails": [],
+                        "metadata": null
+                    }}
+                }}"#,
+                HASH
+            )
+            .as_str(),
+        )?;
+
+        assert_eq!(response.result.tx_hashes(), vec![HASH.to_string()]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod test_simulate {
+    use crate::config::Config;
+    use crate::payload::{Coins, Message};
+    use crate::simulate::{adjust_gas, fee_for_gas};
+    use crate::tx::{encode_any, encode_unsigned_tx};
+    use base64::engine::general_purpose;
+    use base64::Engine;
+    use std::str::FromStr;
+
+    #[test]
+    pub fn encode_message() -> Result<(), anyhow::Error> {
+        let message =
+            Message::build_bank_send("a".to_string(), "b".to_string(), Coins::from_str("5uatom")?);
+
+        let mut expected = vec![0x0a, 0x1c];
+        expected.extend_from_slice(b"/cosmos.bank.v1beta1.MsgSend");
+        expected.extend_from_slice(&[0x12, 0x12, 0x0a, 0x01, b'a', 0x12, 0x01, b'b', 0x1a, 0x0a]);
+        expected.extend_from_slice(&[0x0a, 0x05]);
+        expected.extend_from_slice(b"uatom");
+        expected.extend_from_slice(&[0x12, 0x01, b'5']);
+
+        assert_eq!(encode_any(&message)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn encode_ints_and_bytes() -> Result<(), anyhow::Error> {
+        let message = Message::build_contract_migrate(
+            "a".to_string(),
+            "b".to_string(),
+            300,
+            general_purpose::STANDARD.encode("{}"),
+        );
+
+        let any = encode_any(&message)?;
+        assert!(any.ends_with(&[
+            0x0a, 0x01, b'a', 0x12, 0x01, b'b', 0x18, 0xac, 0x02, 0x22, 0x02, b'{', b'}'
+        ]));
+
+        let message =
+            Message::build_contract_migrate("a".to_string(), "b".to_string(), 1, "%%".to_string());
+        assert!(encode_any(&message).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn encode_tx() -> Result<(), anyhow::Error> {
+        let message =
+            Message::build_bank_send("a".to_string(), "b".to_string(), Coins::from_str("5uatom")?);
+        let tx = encode_unsigned_tx(&[message], &[3], 0)?;
+
+        // one empty signature per signer at the end of TxRaw
+        assert_eq!(&tx[tx.len() - 2..], &[0x1a, 0x00]);
+        assert_eq!(tx[0], 0x0a);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn gas_adjustment() -> Result<(), anyhow::Error> {
+        assert_eq!(adjust_gas(100000, 1.0)?, 100000);
+        assert_eq!(adjust_gas(100000, 1.3)?, 130000);
+        assert_eq!(adjust_gas(3, 1.5)?, 5);
+        assert!(adjust_gas(100000, 0.5).is_err());
+        assert!(adjust_gas(100000, f64::NAN).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn fee() -> Result<(), anyhow::Error> {
+        let cfg = Config::load()?;
+        let mut chain = cfg.find_chain(&"kichain-t-4".to_string())?;
+
+        assert!(fee_for_gas(&chain, 200000).is_err());
+
+        chain.gas_price = Some("0.025".to_string());
+        assert_eq!(fee_for_gas(&chain, 200000)?.to_string(), "5000utki");
+        assert_eq!(fee_for_gas(&chain, 1)?.to_string(), "1utki");
+
+        chain.gas_price = Some("0".to_string());
+        assert!(fee_for_gas(&chain, 200000)?.is_zero());
+
+        Ok(())
+    }
+}
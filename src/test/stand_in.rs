@@ -0,0 +1,91 @@
+#![cfg(test)]
+//! A local HTTP server standing in for Taurus or a notification channel.
+
+use crate::config::{AuthMode, Taurus as TaurusConfig};
+use crate::taurus::Taurus;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+/// A request received by the stand-in.
+#[derive(Clone, Debug, Default)]
+pub struct Received {
+    pub method: String,
+    pub path: String,
+    pub authorization: Option<String>,
+    pub body: String,
+}
+
+/// Answers one request per `(status, body)` of `responses`, in order, and
+/// returns the address to reach it with the requests it received.
+pub fn stand_in(responses: &[(&str, &str)]) -> (String, thread::JoinHandle<Vec<Received>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let responses: Vec<(String, String)> = responses
+        .iter()
+        .map(|(status, body)| (status.to_string(), body.to_string()))
+        .collect();
+
+    let handle = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, body)| {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut received = Received::default();
+                let mut length = 0;
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut request_line = line.split_whitespace();
+                received.method = request_line.next().unwrap_or_default().to_string();
+                received.path = request_line.next().unwrap_or_default().to_string();
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        } else if name.eq_ignore_ascii_case("authorization") {
+                            received.authorization = Some(value.trim().to_string());
+                        }
+                    }
+                }
+
+                let mut data = vec![0; length];
+                reader.read_exact(&mut data).unwrap();
+                received.body = String::from_utf8(data).unwrap();
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+
+                received
+            })
+            .collect()
+    });
+
+    (address, handle)
+}
+
+/// A Taurus client for the stand-in at `address`, with an api key so that
+/// there is no login round trip.
+pub fn taurus(address: &str) -> Result<Taurus, anyhow::Error> {
+    Taurus::new(&TaurusConfig {
+        api_url: address.to_string(),
+        mail: String::new(),
+        passwd: String::new(),
+        auth: AuthMode::ApiKey,
+        api_key: Some("k-123".to_string()),
+        api_secret: Some("00112233445566778899aabbccddeeff".to_string()),
+    })
+}
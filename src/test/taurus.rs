@@ -0,0 +1,577 @@
+#[cfg(test)]
+mod test_taurus {
+    use crate::taurus::{
+        all_pages, check_scores, parse_version, supported_version, AddressType, AddressesResponse,
+        AttributeParams, ContractType, CurrencyInfo, HealthReport, NodeInfo, OutgoingParams,
+        RequestInfos, RequestStatus, ScoreProvider, Transaction, WhitelistParams, WhitelistStatus,
+        WhitelistedAddress, WhitelistedAddressesResponse,
+    };
+    use crate::test::stand_in::{stand_in, taurus};
+    use std::str::FromStr;
+
+    fn currency(id: &str, decimals: &str) -> Result<CurrencyInfo, anyhow::Error> {
+        Ok(serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "symbol": id,
+            "blockchain": "ETH",
+            "decimals": decimals,
+            "contractAddress": null,
+            "enabled": true,
+            "displayName": id,
+            "type": "native"
+        }))?)
+    }
+
+    #[test]
+    pub fn whitelisted_addresses() -> Result<(), anyhow::Error> {
+        let response: WhitelistedAddressesResponse = serde_json::from_str(
+            r#"{
+                "result": [
+                    {
+                        "id": "1",
+                        "blockchain": "ATOM",
+                        "network": "mainnet",
+                        "status": "Approved",
+                        "address": "cosmos1abc",
+                        "label": "payout"
+                    },
+                    {
+                        "id": "2",
+                        "blockchain": "ETH",
+                        "status": "PENDING",
+                        "metadata": {
+                            "hash": "00",
+                            "payload": {
+                                "address": "0xabc",
+                                "blockchain": "ETH"
+                            }
+                        }
+                    },
+                    {
+                        "id": "3",
+                        "status": "SomethingNew"
+                    }
+                ],
+                "totalItems": "3"
+            }"#,
+        )?;
+
+        let addresses = response.result.unwrap();
+        assert_eq!(addresses[0].status, WhitelistStatus::Approved);
+        assert!(addresses[0].is_approved());
+        assert!(addresses[0].matches("cosmos1abc", "atom"));
+        assert!(!addresses[0].matches("cosmos1abc", "ETH"));
+
+        assert_eq!(addresses[1].status, WhitelistStatus::Pending);
+        assert_eq!(addresses[1].address().as_deref(), Some("0xabc"));
+        assert!(addresses[1].matches("0xabc", "ETH"));
+        assert!(!addresses[1].is_approved());
+
+        assert_eq!(addresses[2].status, WhitelistStatus::Unknown);
+        assert!(!addresses[2].matches("cosmos1abc", "ATOM"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn pagination() -> Result<(), anyhow::Error> {
+        let page = |offset: usize, total: Option<&str>| -> WhitelistedAddressesResponse {
+            let result = (offset..(offset + 2).min(5))
+                .map(|id| {
+                    serde_json::from_value::<WhitelistedAddress>(serde_json::json!({
+                        "id": id.to_string(),
+                        "status": "Approved"
+                    }))
+                    .unwrap()
+                })
+                .collect();
+            WhitelistedAddressesResponse {
+                result: Some(result),
+                total_items: total.map(|t| t.to_string()),
+            }
+        };
+
+        // Taurus caps the limit: pages are short until the total is reached
+        let mut offsets = Vec::new();
+        let items = all_pages(100, |_, offset| {
+            offsets.push(offset);
+            Ok(page(offset, Some("5")))
+        })?;
+        assert_eq!(items.len(), 5);
+        assert_eq!(offsets, [0, 2, 4]);
+
+        // without a total, until an empty page
+        let mut offsets = Vec::new();
+        let items = all_pages(100, |_, offset| {
+            offsets.push(offset);
+            Ok(page(offset, None))
+        })?;
+        assert_eq!(items.len(), 5);
+        assert_eq!(offsets, [0, 2, 4, 5]);
+
+        let items = all_pages(100, |_, _| {
+            Ok(WhitelistedAddressesResponse {
+                result: None,
+                total_items: Some("5".to_string()),
+            })
+        })?;
+        assert!(items.is_empty());
+
+        assert!(
+            all_pages::<WhitelistedAddressesResponse>(100, |_, _| anyhow::bail!("down")).is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn whitelist_params_match() -> Result<(), anyhow::Error> {
+        let response: WhitelistedAddressesResponse = serde_json::from_str(
+            r#"{
+                "result": [
+                    {
+                        "id": "1",
+                        "blockchain": "ETH",
+                        "status": "Pending",
+                        "address": "0xabc",
+                        "contractType": "ERC20"
+                    },
+                    {
+                        "id": "2",
+                        "blockchain": "ETH",
+                        "status": "Rejected",
+                        "address": "0xdef"
+                    }
+                ]
+            }"#,
+        )?;
+        let addresses = response.result.unwrap();
+
+        let mut params = WhitelistParams {
+            blockchain: Some("eth".to_string()),
+            label: "token".to_string(),
+            address: "0xabc".to_string(),
+            address_type: AddressType::Contract,
+            contract_type: Some(ContractType::Erc20),
+        };
+        assert!(addresses[0].matches_params(&params));
+
+        params.contract_type = Some(ContractType::Erc721);
+        assert!(!addresses[0].matches_params(&params));
+
+        params.contract_type = None;
+        params.blockchain = None;
+        assert!(addresses[0].matches_params(&params));
+
+        params.address = "0xdef".to_string();
+        assert!(!addresses[1].matches_params(&params));
+
+        // EVM addresses differ only by their checksum case
+        params.address = "0xABC".to_string();
+        assert!(addresses[0].matches_params(&params));
+        assert!(addresses[0].matches("0xAbC", "ETH"));
+
+        let cosmos: WhitelistedAddress = serde_json::from_str(
+            r#"{
+                "id": "3",
+                "blockchain": "ATOM",
+                "status": "Approved",
+                "address": "cosmos1abc"
+            }"#,
+        )?;
+        assert!(cosmos.matches("cosmos1abc", "ATOM"));
+        assert!(!cosmos.matches("COSMOS1ABC", "ATOM"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn ensure_whitelisted() -> Result<(), anyhow::Error> {
+        let cosmos = crate::address::encode("cosmos", &[1u8; 20])?;
+        let params = WhitelistParams::address("ATOM", "payout", &cosmos, AddressType::Individual)?;
+
+        // an existing entry is returned without creating another one
+        let existing = serde_json::json!({
+            "result": [{"id": "1", "blockchain": "ATOM", "status": "Pending", "address": cosmos}],
+            "totalItems": "1"
+        })
+        .to_string();
+        let (address, handle) = stand_in(&[("200 OK", existing.as_str())]);
+        let whitelisted = taurus(address.as_str())?.ensure_whitelisted(params.clone())?;
+        assert_eq!(whitelisted.id, "1");
+        let received = handle.join().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].method, "GET");
+
+        // a missing one is created
+        let (address, handle) = stand_in(&[
+            ("200 OK", r#"{"result":[],"totalItems":"0"}"#),
+            ("200 OK", r#"{"result":{"id":"9"}}"#),
+        ]);
+        assert_eq!(
+            taurus(address.as_str())?.ensure_whitelisted(params)?.id,
+            "9"
+        );
+        let received = handle.join().unwrap();
+        assert_eq!(received[1].method, "POST");
+        assert_eq!(received[1].path, "/api/rest/v1/whitelists/addresses");
+        let body: serde_json::Value = serde_json::from_str(received[1].body.as_str())?;
+        assert_eq!(body["address"], cosmos);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn whitelist_params() -> Result<(), anyhow::Error> {
+        let token = "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984";
+
+        let params = WhitelistParams::contract("ETH", "uni", token, ContractType::Erc20)?;
+        assert_eq!(
+            serde_json::to_value(&params)?,
+            serde_json::json!({
+                "blockchain": "ETH",
+                "label": "uni",
+                "address": token,
+                "addressType": "contract",
+                "contractType": "ERC20"
+            })
+        );
+
+        assert!(WhitelistParams::contract("ETH", "uni", "0x1234", ContractType::Erc20).is_err());
+        assert!(WhitelistParams::address("ETH", "me", token, AddressType::Contract).is_err());
+        assert!(WhitelistParams::address("ETH", "me", token, AddressType::Individual).is_ok());
+        assert!(WhitelistParams::address("ATOM", "me", token, AddressType::Exchange).is_err());
+
+        let cosmos = crate::address::encode("cosmos", &[3u8; 20])?;
+        assert!(WhitelistParams::address("ATOM", "me", &cosmos, AddressType::Exchange).is_ok());
+
+        let params = WhitelistParams {
+            address: token.to_string(),
+            address_type: AddressType::Contract,
+            ..Default::default()
+        };
+        assert!(params.validate().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn request_status() -> Result<(), anyhow::Error> {
+        assert_eq!(
+            RequestStatus::from_str("CONFIRMED")?,
+            RequestStatus::Confirmed
+        );
+        assert_eq!(
+            RequestStatus::from_str("hsm_signed")?,
+            RequestStatus::Signed
+        );
+        assert_eq!(RequestStatus::from_str("WHATEVER")?, RequestStatus::Unknown);
+
+        assert!(RequestStatus::Confirmed.is_final());
+        assert!(RequestStatus::Rejected.is_final());
+        assert!(!RequestStatus::Rejected.is_success());
+        assert!(!RequestStatus::Broadcasted.is_final());
+
+        let request = RequestInfos {
+            status: "BROADCASTING".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(request.request_status(), RequestStatus::Broadcasting);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn outgoing_params() -> Result<(), anyhow::Error> {
+        let params = OutgoingParams {
+            from_address_id: "1".to_string(),
+            to_whitelisted_address_id: "2".to_string(),
+            amount: "250000000000000000".to_string(),
+            currency: Some("ETH".to_string()),
+            comment: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&params)?,
+            serde_json::json!({
+                "fromAddressId": "1",
+                "toWhitelistedAddressId": "2",
+                "amount": "250000000000000000",
+                "currency": "ETH",
+                "comment": null
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn outgoing_amount() -> Result<(), anyhow::Error> {
+        // refused before anything is sent
+        let taurus = taurus("http://127.0.0.1:1")?;
+
+        for amount in ["0", "", "abc", "-1", "0.5"] {
+            let params = OutgoingParams {
+                from_address_id: "1".to_string(),
+                to_whitelisted_address_id: "2".to_string(),
+                amount: amount.to_string(),
+                ..Default::default()
+            };
+            let error = taurus.outgoing_request(&params).unwrap_err();
+            assert!(error.to_string().contains("invalid transfer amount"));
+        }
+
+        let usdc = currency("USDC", "6")?;
+        assert!(taurus.transfer("1", "2", "0", &usdc).is_err());
+        assert!(taurus.transfer("1", "2", "-1", &usdc).is_err());
+        // more decimals than the currency has
+        assert!(taurus.transfer("1", "2", "0.0000001", &usdc).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn transfer() -> Result<(), anyhow::Error> {
+        let (address, handle) = stand_in(&[(
+            "200 OK",
+            r#"{"result":{"id":"7","tenantId":"1","currency":"ETH","envelope":"","status":"CREATED","type":"transfer","signedRequests":null,"trails":[],"metadata":null}}"#,
+        )]);
+
+        let response =
+            taurus(address.as_str())?.transfer("1", "2", "0.25", &currency("ETH", "18")?)?;
+        assert_eq!(response.result.id, "7");
+
+        let body: serde_json::Value =
+            serde_json::from_str(handle.join().unwrap()[0].body.as_str())?;
+        assert_eq!(body["fromAddressId"], "1");
+        assert_eq!(body["toWhitelistedAddressId"], "2");
+        assert_eq!(body["amount"], "250000000000000000");
+        assert_eq!(body["currency"], "ETH");
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn eth_transfer() -> Result<(), anyhow::Error> {
+        let taurus = taurus("http://127.0.0.1:1")?;
+        let atom: CurrencyInfo = serde_json::from_value(serde_json::json!({
+            "id": "ATOM",
+            "name": "Cosmos",
+            "symbol": "ATOM",
+            "blockchain": "COSMOS",
+            "decimals": "6",
+            "contractAddress": null,
+            "enabled": true,
+            "displayName": "Cosmos Atom",
+            "type": "native"
+        }))?;
+
+        let error = taurus.eth_transfer("1", "2", "1", &atom).unwrap_err();
+        assert!(error.to_string().contains("not on an EVM blockchain"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn attributes() -> Result<(), anyhow::Error> {
+        let response: AddressesResponse = serde_json::from_str(
+            r#"{
+                "result": [
+                    {
+                        "id": "12",
+                        "walletId": "3",
+                        "addressPath": "m/44'/118'/0'/0/0",
+                        "address": "cosmos1abc",
+                        "label": "custody",
+                        "signature": "",
+                        "attributes": [
+                            {
+                                "id": "7",
+                                "key": "customer",
+                                "value": "acme",
+                                "contentType": "text/plain",
+                                "owner": "alice@taurus.io",
+                                "type": "",
+                                "subtype": "",
+                                "isfile": false
+                            }
+                        ]
+                    },
+                    {
+                        "id": "13",
+                        "walletId": "3",
+                        "addressPath": "m/44'/118'/0'/0/1",
+                        "address": "cosmos1def",
+                        "label": "fees",
+                        "signature": ""
+                    }
+                ],
+                "totalItems": "2"
+            }"#,
+        )?;
+        let addresses = response.result.unwrap_or_default();
+
+        assert_eq!(addresses[0].attribute("customer"), Some("acme"));
+        assert_eq!(addresses[0].attributes[0].id, "7");
+        assert!(addresses[0].attributes[0].matches("customer", "acme"));
+        assert!(!addresses[0].attributes[0].matches("customer", "other"));
+        assert_eq!(addresses[1].attribute("customer"), None);
+
+        let params = AttributeParams {
+            attribute_type: Some("tag".to_string()),
+            ..AttributeParams::new("purpose", "payout")
+        };
+        params.validate()?;
+        assert_eq!(
+            serde_json::to_value(&params)?,
+            serde_json::json!({ "key": "purpose", "value": "payout", "type": "tag" })
+        );
+        assert!(AttributeParams::new(" ", "payout").validate().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn scores() -> Result<(), anyhow::Error> {
+        let whitelisted: WhitelistedAddress = serde_json::from_str(
+            r#"{
+                "id": "1",
+                "blockchain": "ETH",
+                "status": "Approved",
+                "address": "0xabc",
+                "scores": [
+                    {
+                        "id": "10",
+                        "provider": "chainalysis",
+                        "type": "out",
+                        "score": "HIGH",
+                        "updateDate": "2023-03-01T08:30:00Z"
+                    },
+                    {
+                        "id": "11",
+                        "provider": "Scorechain",
+                        "type": "out",
+                        "score": "82.5",
+                        "updateDate": "2023-03-01T08:30:00Z"
+                    },
+                    {
+                        "id": "12",
+                        "provider": "acme-risk",
+                        "type": "in",
+                        "score": 12
+                    }
+                ]
+            }"#,
+        )?;
+
+        let scores = &whitelisted.scores;
+        assert_eq!(scores[0].provider, ScoreProvider::Chainalysis);
+        assert_eq!(scores[0].score, None);
+        assert_eq!(scores[1].provider, ScoreProvider::Scorechain);
+        assert_eq!(scores[1].score, Some(82.5));
+        assert_eq!(
+            scores[2].provider,
+            ScoreProvider::Other("acme-risk".to_string())
+        );
+        assert_eq!(scores[2].score, Some(12.0));
+        assert_eq!(scores[2].update_date, None);
+
+        // the "HIGH" rating cannot be compared so fails closed
+        assert!(scores[0].exceeds(100.0));
+        assert!(whitelisted.check_risk(90.0).is_err());
+        assert!(check_scores("0xabc", &scores[1..], 90.0).is_ok());
+        assert!(check_scores("0xabc", &scores[1..], 80.0).is_err());
+        assert!(check_scores("0xabc", &[], 0.0).is_ok());
+
+        let transaction: Transaction = serde_json::from_str(
+            r#"{
+                "id": "42",
+                "direction": "incoming",
+                "currency": "ETH",
+                "hash": "0x01",
+                "amount": "1000",
+                "scores": [{ "id": "1", "provider": "elliptic", "type": "in", "score": "4.2" }]
+            }"#,
+        )?;
+        assert_eq!(transaction.scores[0].provider, ScoreProvider::Elliptic);
+        assert!(transaction.scores[0].exceeds(4.0));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn health() -> Result<(), anyhow::Error> {
+        let node: NodeInfo = serde_json::from_str(
+            r#"{
+                "name": "taurus-protect",
+                "version": "v3.12.1-rc1",
+                "runtime_environment": "production",
+                "id": "node-1",
+                "commit": "3f2a1b0"
+            }"#,
+        )?;
+
+        assert_eq!(parse_version(node.version.as_str()), vec![3, 12, 1]);
+        assert_eq!(parse_version("0.47"), vec![0, 47]);
+        assert!(parse_version("unknown").is_empty());
+
+        assert!(supported_version("3"));
+        assert!(supported_version("v3.0.0"));
+        assert!(!supported_version("2.99"));
+        assert!(!supported_version("unknown"));
+
+        let report = HealthReport {
+            reachable: true,
+            authenticated: true,
+            version_supported: true,
+            node: Some(node),
+            ..Default::default()
+        };
+        assert!(report.is_healthy());
+        assert_eq!(serde_json::to_value(&report)?["node"]["id"], "node-1");
+        assert!(!HealthReport {
+            authenticated: false,
+            ..report
+        }
+        .is_healthy());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn health_check() -> Result<(), anyhow::Error> {
+        let node = |version: &str| {
+            serde_json::json!({
+                "name": "taurus-protect",
+                "version": version,
+                "runtime_environment": "test",
+                "id": "node-1",
+                "commit": "3f2a1b0"
+            })
+            .to_string()
+        };
+
+        let current = node("3");
+        let (address, handle) = stand_in(&[("200 OK", current.as_str()), ("200 OK", "{}")]);
+        let report = taurus(address.as_str())?.health_check();
+        assert!(report.is_healthy(), "{:?}", report.errors);
+        assert!(handle.join().unwrap()[1]
+            .path
+            .starts_with("/api/rest/v1/wallets"));
+
+        let outdated = node("2.9.0");
+        let (address, handle) = stand_in(&[
+            ("200 OK", outdated.as_str()),
+            ("401 Unauthorized", r#"{"error":"invalid signature"}"#),
+        ]);
+        let report = taurus(address.as_str())?.health_check();
+        handle.join().unwrap();
+        assert!(report.reachable);
+        assert!(!report.version_supported);
+        assert!(!report.authenticated);
+        assert_eq!(report.errors.len(), 2);
+
+        Ok(())
+    }
+}
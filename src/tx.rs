@@ -0,0 +1,125 @@
+use crate::payload::{
+    Message, MesssageDetails, Objects, KIND_BYTES, KIND_INT, KIND_MESSAGE, KIND_STRING,
+};
+use anyhow::{anyhow, bail};
+use base64::engine::general_purpose;
+use base64::Engine;
+
+const WIRE_VARINT: u32 = 0;
+const WIRE_LEN: u32 = 2;
+const SIGN_MODE_DIRECT: u64 = 1;
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_tag(out: &mut Vec<u8>, field_id: u32, wire_type: u32) {
+    write_varint(out, ((field_id << 3) | wire_type) as u64);
+}
+
+fn write_bytes(out: &mut Vec<u8>, field_id: u32, data: &[u8]) {
+    write_tag(out, field_id, WIRE_LEN);
+    write_varint(out, data.len() as u64);
+    out.extend_from_slice(data);
+}
+
+fn write_uint(out: &mut Vec<u8>, field_id: u32, value: u64) {
+    write_tag(out, field_id, WIRE_VARINT);
+    write_varint(out, value);
+}
+
+fn parse_int(value: &str) -> Result<u64, anyhow::Error> {
+    match value.parse::<u64>() {
+        Ok(v) => Ok(v),
+        Err(_) => Ok(value
+            .parse::<i64>()
+            .map_err(|_| anyhow!("invalid int value: {}", value))? as u64),
+    }
+}
+
+fn encode_object(
+    out: &mut Vec<u8>,
+    kind: &str,
+    field_id: u32,
+    object: &Objects,
+) -> Result<(), anyhow::Error> {
+    match (kind, object) {
+        (KIND_STRING, Objects::String(s)) => write_bytes(out, field_id, s.string.as_bytes()),
+        (KIND_BYTES, Objects::Bytes(b)) => {
+            write_bytes(out, field_id, &general_purpose::STANDARD.decode(&b.bytes)?)
+        }
+        (KIND_INT, Objects::Int(i)) => write_uint(out, field_id, parse_int(&i.int)?),
+        (KIND_MESSAGE, Objects::Message(m)) => {
+            write_bytes(out, field_id, &encode_fields(&m.message)?)
+        }
+        (_, Objects::Array(a)) => {
+            for elem in a.array.elems.iter() {
+                encode_object(out, a.array.kind.as_str(), field_id, elem)?;
+            }
+        }
+        _ => bail!("field {} does not match kind {}", field_id, kind),
+    }
+
+    Ok(())
+}
+
+fn encode_fields(fields: &[MesssageDetails]) -> Result<Vec<u8>, anyhow::Error> {
+    let mut out = Vec::new();
+    for field in fields.iter() {
+        encode_object(&mut out, field.kind.as_str(), field.field_id, &field.value)
+            .map_err(|e| anyhow!("{}: {}", field.name, e))?;
+    }
+    Ok(out)
+}
+
+/// Encodes a payload message as a protobuf `google.protobuf.Any`.
+pub fn encode_any(message: &Message) -> Result<Vec<u8>, anyhow::Error> {
+    let mut out = Vec::new();
+    write_bytes(&mut out, 1, message.url.as_bytes());
+    write_bytes(&mut out, 2, &encode_fields(&message.message)?);
+    Ok(out)
+}
+
+/// Builds the `TxRaw` bytes of an unsigned transaction suitable for
+/// `/cosmos/tx/v1beta1/simulate`: one empty signature and no public key per
+/// signer, which the SDK replaces with simulated ones.
+pub fn encode_unsigned_tx(
+    messages: &[Message],
+    sequences: &[u64],
+    gas_limit: u64,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let mut body = Vec::new();
+    for message in messages.iter() {
+        write_bytes(&mut body, 1, &encode_any(message)?);
+    }
+
+    let mut auth_info = Vec::new();
+    for sequence in sequences.iter() {
+        let mut single = Vec::new();
+        write_uint(&mut single, 1, SIGN_MODE_DIRECT);
+        let mut mode_info = Vec::new();
+        write_bytes(&mut mode_info, 1, &single);
+
+        let mut signer_info = Vec::new();
+        write_bytes(&mut signer_info, 2, &mode_info);
+        write_uint(&mut signer_info, 3, *sequence);
+
+        write_bytes(&mut auth_info, 1, &signer_info);
+    }
+    let mut fee = Vec::new();
+    write_uint(&mut fee, 2, gas_limit);
+    write_bytes(&mut auth_info, 2, &fee);
+
+    let mut tx = Vec::new();
+    write_bytes(&mut tx, 1, &body);
+    write_bytes(&mut tx, 2, &auth_info);
+    for _ in sequences.iter() {
+        write_bytes(&mut tx, 3, &[]);
+    }
+
+    Ok(tx)
+}